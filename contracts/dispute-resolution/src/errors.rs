@@ -1,7 +1,8 @@
 use soroban_sdk::contracterror;
 
 #[contracterror]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
 pub enum Error {
     NotFound = 1,
     AlreadyExists = 2,
@@ -12,4 +13,19 @@ pub enum Error {
     VotingPeriodEnded = 7,
     InvalidReason = 8,
     SplitNotFound = 9,
+    NotTied = 10,
+    InvalidDecision = 11,
+    InvalidPolicy = 12,
+    NotInitialized = 13,
+    NotVoted = 14,
+    NotResolved = 15,
+    ReopenWindowClosed = 16,
+    CommentTooLong = 17,
+    TooManyComments = 18,
+    VoteCooldown = 19,
+    InvalidCategory = 20,
+    InvalidTarget = 21,
+    MemoTooLong = 22,
+    VoteDirectionUnknown = 23,
+    EarlyResolutionNotMet = 24,
 }
\ No newline at end of file
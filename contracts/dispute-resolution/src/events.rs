@@ -0,0 +1,47 @@
+use soroban_sdk::{Address, Env, String, Symbol};
+use crate::types::DisputeResult;
+
+/// Emitted after each vote with the running tally.
+pub fn emit_vote_cast(
+    env: &Env,
+    dispute_id: &String,
+    voter: &Address,
+    support: bool,
+    votes_for: u32,
+    votes_against: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "vote_cast"), dispute_id.clone()),
+        (voter.clone(), support, votes_for, votes_against),
+    );
+}
+
+/// Emitted when a voter withdraws their vote, with the tally after it's taken back.
+pub fn emit_vote_revoked(
+    env: &Env,
+    dispute_id: &String,
+    voter: &Address,
+    votes_for: u32,
+    votes_against: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "vote_revoked"), dispute_id.clone()),
+        (voter.clone(), votes_for, votes_against),
+    );
+}
+
+/// Emitted when the admin overrides a tied result.
+pub fn emit_tie_broken(env: &Env, dispute_id: &String, admin: &Address, decision: &DisputeResult) {
+    env.events().publish(
+        (Symbol::new(env, "tie_broken"), dispute_id.clone()),
+        (admin.clone(), decision.clone()),
+    );
+}
+
+/// Emitted when the admin reopens a resolved dispute for another vote.
+pub fn emit_dispute_reopened(env: &Env, dispute_id: &String, admin: &Address, voting_ends_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "dispute_reopened"), dispute_id.clone()),
+        (admin.clone(), voting_ends_at),
+    );
+}
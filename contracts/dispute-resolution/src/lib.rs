@@ -1,70 +1,469 @@
 #![no_std]
 
 mod errors;
+mod events;
 mod storage;
 mod types;
 
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Bytes, Env, String, Address};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, vec, xdr::ToXdr, Address, Bytes, Env, IntoVal, String, Symbol,
+};
 use errors::Error;
-use types::{DataKey, Dispute, DisputeResult, DisputeStatus};
+use types::{Comment, Dispute, DisputeResult, DisputeStatus, LegacyDispute, ResolutionPolicy, Vote};
 
 const VOTING_PERIOD: u64 = 604_800; // 7 days in seconds
+const MIN_VOTING_PERIOD: u64 = 86_400; // 1 day, floor for custom policies
+const MAX_VOTING_PERIOD: u64 = 31_536_000; // 365 days, also caps participant grace
+const DEFAULT_MIN_VOTES: u32 = 0;
+const DEFAULT_MIN_DISTINCT_VOTERS: u32 = 0;
+const DEFAULT_MIN_VOTES_FOR_EARLY: u32 = 5;
+const DEFAULT_SUPERMAJORITY_RATIO: u32 = 5_000; // simple majority, in basis points
+const DEFAULT_EARLY_RATIO: u32 = 6_667; // two thirds, in basis points
+const BPS_DENOMINATOR: u32 = 10_000;
+const DECAY_SCALE: u64 = 1_000; // full weight of a vote cast at the start of the window
+const MIN_DECAYED_WEIGHT: u32 = 1; // floor for late and grace-period votes under decay
+const DEFAULT_REOPEN_WINDOW: u64 = 604_800; // 7 days after resolution
+const MAX_COMMENTS: u32 = 50; // per dispute
+const MAX_COMMENTS_PER_AUTHOR: u32 = 10; // so one party can't fill the log alone
+const MAX_COMMENT_LEN: u32 = 280; // bytes
+const MAX_MEMO_LEN: u32 = 140; // bytes
+const UNKNOWN_VOTE_CAST_AT: u64 = u64::MAX; // marks migrated votes whose direction was never stored
+
+fn default_policy() -> ResolutionPolicy {
+    ResolutionPolicy {
+        min_votes: DEFAULT_MIN_VOTES,
+        min_distinct_voters: DEFAULT_MIN_DISTINCT_VOTERS,
+        supermajority_ratio: DEFAULT_SUPERMAJORITY_RATIO,
+        voting_period: VOTING_PERIOD,
+        tie_break: DisputeResult::Tied,
+        participant_grace_secs: 0,
+        min_votes_for_early: DEFAULT_MIN_VOTES_FOR_EARLY,
+        early_ratio: DEFAULT_EARLY_RATIO,
+    }
+}
+
+fn validate_policy(policy: &ResolutionPolicy) -> Result<(), Error> {
+    if policy.supermajority_ratio < DEFAULT_SUPERMAJORITY_RATIO
+        || policy.supermajority_ratio >= BPS_DENOMINATOR
+        || policy.early_ratio < DEFAULT_SUPERMAJORITY_RATIO
+        || policy.early_ratio >= BPS_DENOMINATOR
+        || policy.voting_period < MIN_VOTING_PERIOD
+        || policy.voting_period > MAX_VOTING_PERIOD
+        || policy.participant_grace_secs > MAX_VOTING_PERIOD
+        || (policy.min_votes == 0 && policy.min_distinct_voters == 0)
+        || policy.tie_break == DisputeResult::NoQuorum
+        || policy.tie_break == DisputeResult::Pending
+    {
+        return Err(Error::InvalidPolicy);
+    }
+    Ok(())
+}
+
+/// Store a new dispute in `Voting` under a policy the caller has already checked.
+#[allow(clippy::too_many_arguments)]
+fn open_dispute(
+    env: &Env,
+    split_id: String,
+    raiser: Address,
+    reason: String,
+    category: Symbol,
+    target: Option<Address>,
+    policy: ResolutionPolicy,
+    decay_enabled: bool,
+) -> Result<String, Error> {
+    raiser.require_auth();
+    ensure_participant(env, &split_id, &raiser)?;
+    validate_category(&category)?;
+    validate_target(env, &split_id, &target)?;
+
+    let now = env.ledger().timestamp();
+    let dispute_id = generate_dispute_id(env, &split_id);
+
+    if storage::has_dispute(env, &dispute_id) {
+        return Err(Error::AlreadyExists);
+    }
+
+    let dispute = Dispute {
+        dispute_id: dispute_id.clone(),
+        split_id,
+        raiser: raiser.clone(),
+        reason,
+        category: category.clone(),
+        target,
+        status: DisputeStatus::Voting,
+        votes_for: 0,
+        votes_against: 0,
+        voters: soroban_sdk::Vec::new(env),
+        created_at: now,
+        voting_ends_at: now
+            .checked_add(policy.voting_period)
+            .ok_or(Error::InvalidPolicy)?,
+        result: DisputeResult::Pending,
+        resolved_at: None,
+        resolved_by: None,
+        resolution_policy: policy,
+        decay_enabled,
+        vote_cooldown_secs: 0,
+    };
+
+    storage::save_dispute(env, &dispute);
+    storage::add_to_list(env, dispute_id.clone());
+    storage::add_to_raiser_index(env, &raiser, dispute_id.clone());
+    storage::add_to_category_index(env, &category, dispute_id.clone());
+    storage::set_active_count(env, storage::get_active_count(env) + 1);
+
+    Ok(dispute_id)
+}
+
+fn validate_category(category: &Symbol) -> Result<(), Error> {
+    let allowed = [
+        symbol_short!("payment"),
+        symbol_short!("fraud"),
+        symbol_short!("miscomm"),
+        symbol_short!("other"),
+    ];
+    if !allowed.contains(category) {
+        return Err(Error::InvalidCategory);
+    }
+    Ok(())
+}
+
+/// Check with the escrow contract that `raiser` is a participant of `split_id`.
+/// Skipped unless the admin has turned participant enforcement on.
+fn ensure_participant(env: &Env, split_id: &String, raiser: &Address) -> Result<(), Error> {
+    if !storage::get_require_participant(env) {
+        return Ok(());
+    }
+
+    let escrow = storage::get_escrow_contract(env).ok_or(Error::NotInitialized)?;
+    if !is_escrow_participant(env, &escrow, split_id, raiser) {
+        return Err(Error::NotAuthorized);
+    }
+    Ok(())
+}
+
+/// Check that a dispute's target is a participant of the split.
+/// Skipped for untargeted disputes and when no escrow contract is linked.
+fn validate_target(env: &Env, split_id: &String, target: &Option<Address>) -> Result<(), Error> {
+    if let (Some(target), Some(escrow)) = (target, storage::get_escrow_contract(env)) {
+        if !is_escrow_participant(env, &escrow, split_id, target) {
+            return Err(Error::InvalidTarget);
+        }
+    }
+    Ok(())
+}
+
+fn is_escrow_participant(env: &Env, escrow: &Address, split_id: &String, address: &Address) -> bool {
+    env.invoke_contract(
+        escrow,
+        &Symbol::new(env, "is_participant"),
+        vec![env, split_id.into_val(env), address.into_val(env)],
+    )
+}
+
+/// Whether `address` raised, voted on, or is a participant of the split behind a dispute.
+fn is_dispute_party(env: &Env, dispute: &Dispute, address: &Address) -> bool {
+    if dispute.raiser == *address || dispute.voters.contains(address) {
+        return true;
+    }
+    match storage::get_escrow_contract(env) {
+        Some(escrow) => is_escrow_participant(env, &escrow, &dispute.split_id, address),
+        None => false,
+    }
+}
 
 fn generate_dispute_id(env: &Env, split_id: &String) -> String {
     let mut input = Bytes::new(env);
-    input.append(&split_id.to_bytes());
+    input.append(&split_id.clone().to_xdr(env));
     let seq = env.ledger().sequence().to_be_bytes();
     input.append(&Bytes::from_slice(env, &seq));
     let hash = env.crypto().sha256(&input);
     let hash_bytes = &hash.to_array()[..8];
-    let mut id_bytes = String::from_str(env, "dis_").to_bytes();
-    id_bytes.append(&Bytes::from_slice(env, hash_bytes));
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut id_bytes = [0u8; 20];
+    id_bytes[..4].copy_from_slice(b"dis_");
+    for (i, b) in hash_bytes.iter().enumerate() {
+        id_bytes[4 + i * 2] = HEX[(b >> 4) as usize];
+        id_bytes[5 + i * 2] = HEX[(b & 0x0f) as usize];
+    }
     String::from_bytes(env, &id_bytes)
 }
 
+/// Whether a vote was migrated from a legacy record that didn't store its direction.
+fn is_unknown_vote(vote: &Vote) -> bool {
+    vote.cast_at == UNKNOWN_VOTE_CAST_AT
+}
+
+/// Rewrite a legacy dispute in the current layout, under the default policy.
+///
+/// `storage::get_dispute` calls this the first time it reads a legacy record,
+/// so every entry point sees the current layout.
+fn upgrade_dispute(env: &Env, legacy: LegacyDispute) -> Dispute {
+    let dispute = Dispute {
+        dispute_id: legacy.dispute_id,
+        split_id: legacy.split_id,
+        raiser: legacy.raiser,
+        reason: legacy.reason,
+        category: symbol_short!("other"),
+        target: None,
+        status: legacy.status,
+        votes_for: legacy.votes_for,
+        votes_against: legacy.votes_against,
+        voters: legacy.voters,
+        created_at: legacy.created_at,
+        voting_ends_at: legacy.voting_ends_at,
+        result: legacy.result.unwrap_or(DisputeResult::Pending),
+        resolved_at: None,
+        resolved_by: None,
+        resolution_policy: default_policy(),
+        decay_enabled: false,
+        vote_cooldown_secs: 0,
+    };
+
+    storage::save_dispute(env, &dispute);
+    // Disputes from before the active count existed were never counted
+    if dispute.status == DisputeStatus::Voting {
+        storage::set_active_count(env, storage::get_active_count(env) + 1);
+    }
+    dispute
+}
+
+/// Whether a dispute's outcome should reverse the split's payout.
+fn triggers_payout(dispute: &Dispute) -> bool {
+    dispute.status == DisputeStatus::Resolved
+        && dispute.result == DisputeResult::UpheldForRaiser
+}
+
+fn tally_result(dispute: &Dispute) -> DisputeResult {
+    let policy = &dispute.resolution_policy;
+    let votes_for = dispute.votes_for as u64;
+    let total = votes_for + dispute.votes_against as u64;
+
+    // Quorum needs enough weight and enough distinct voters, so neither
+    // a single heavy vote nor many decayed ones can meet it alone
+    if total < policy.min_votes as u64 || dispute.voters.len() < policy.min_distinct_voters {
+        return DisputeResult::NoQuorum;
+    }
+
+    if votes_for * BPS_DENOMINATOR as u64 > total * policy.supermajority_ratio as u64 {
+        DisputeResult::UpheldForRaiser
+    } else if dispute.votes_for == dispute.votes_against {
+        policy.tie_break.clone()
+    } else {
+        DisputeResult::DismissedForRaiser
+    }
+}
+
+/// Add a vote to the tally, weighted by time remaining when decay is on.
+/// The caller saves the dispute.
+fn apply_vote(env: &Env, dispute: &mut Dispute, voter: &Address, support: bool, now: u64) {
+    let weight = if dispute.decay_enabled {
+        // Grace-period votes have no window left but still count towards the
+        // distinct-voter quorums, so they keep the minimum weight
+        let remaining = dispute.voting_ends_at.saturating_sub(now);
+        let decayed = (DECAY_SCALE * remaining / dispute.resolution_policy.voting_period) as u32;
+        decayed.max(MIN_DECAYED_WEIGHT)
+    } else {
+        1
+    };
+
+    if support {
+        dispute.votes_for += weight;
+    } else {
+        dispute.votes_against += weight;
+    }
+
+    dispute.voters.push_back(voter.clone());
+    storage::record_vote(
+        env,
+        &dispute.dispute_id,
+        voter,
+        &Vote { support, weight, cast_at: now },
+    );
+
+    events::emit_vote_cast(
+        env,
+        &dispute.dispute_id,
+        voter,
+        support,
+        dispute.votes_for,
+        dispute.votes_against,
+    );
+}
+
+/// When the dispute stops taking votes from anyone, including participant grace.
+fn voting_closes_at(dispute: &Dispute) -> u64 {
+    dispute.voting_ends_at + dispute.resolution_policy.participant_grace_secs
+}
+
+/// Whether a split participant may still vote after the regular window.
+fn in_participant_grace(env: &Env, dispute: &Dispute, voter: &Address, now: u64) -> bool {
+    if now > voting_closes_at(dispute) {
+        return false;
+    }
+    match storage::get_escrow_contract(env) {
+        Some(escrow) => is_escrow_participant(env, &escrow, &dispute.split_id, voter),
+        None => false,
+    }
+}
+
+/// The result a dispute can be resolved to before voting closes, if any.
+///
+/// Either side must hold the supermajority, with enough distinct voters to
+/// meet both the early-resolution turnout and the quorum.
+fn early_result(dispute: &Dispute) -> Option<DisputeResult> {
+    let policy = &dispute.resolution_policy;
+    let total = dispute.votes_for as u64 + dispute.votes_against as u64;
+    if dispute.voters.len() < policy.min_votes_for_early.max(policy.min_distinct_voters)
+        || total < policy.min_votes as u64
+    {
+        return None;
+    }
+
+    let threshold = total * policy.early_ratio as u64;
+    if dispute.votes_for as u64 * BPS_DENOMINATOR as u64 > threshold {
+        Some(DisputeResult::UpheldForRaiser)
+    } else if dispute.votes_against as u64 * BPS_DENOMINATOR as u64 > threshold {
+        Some(DisputeResult::DismissedForRaiser)
+    } else {
+        None
+    }
+}
+
+/// Record a dispute's result and take it out of voting.
+fn close_dispute(
+    env: &Env,
+    mut dispute: Dispute,
+    result: DisputeResult,
+    resolver: Option<Address>,
+) -> DisputeResult {
+    dispute.status = DisputeStatus::Resolved;
+    dispute.result = result.clone();
+    dispute.resolved_at = Some(env.ledger().timestamp());
+    dispute.resolved_by = resolver;
+
+    storage::save_dispute(env, &dispute);
+    storage::set_active_count(env, storage::get_active_count(env).saturating_sub(1));
+
+    // TODO: trigger payout logic based on result
+    // if triggers_payout(&dispute) {
+    //     split_client.reverse_split(&dispute.split_id);
+    // }
+
+    result
+}
+
+fn finalize_dispute(
+    env: &Env,
+    dispute_id: &String,
+    resolver: Option<Address>,
+) -> Result<DisputeResult, Error> {
+    let dispute = storage::get_dispute(env, dispute_id)?;
+
+    if dispute.status != DisputeStatus::Voting {
+        return Err(Error::DisputeClosed);
+    }
+
+    // Voting period, including any participant grace, must have ended
+    if env.ledger().timestamp() <= voting_closes_at(&dispute) {
+        return Err(Error::VotingPeriodActive);
+    }
+
+    // Determine result under the dispute's own policy
+    let result = tally_result(&dispute);
+
+    Ok(close_dispute(env, dispute, result, resolver))
+}
+
 #[contract]
 pub struct DisputeContract;
 
 #[contractimpl]
 impl DisputeContract {
 
-    /// Raise a new dispute against a split.
+    /// Set the admin allowed to perform privileged overrides and the escrow contract disputes act on.
+    pub fn initialize(env: Env, admin: Address, escrow_contract: Address) {
+        if storage::has_admin(&env) {
+            panic!("Contract already initialized");
+        }
+
+        admin.require_auth();
+        storage::set_admin(&env, &admin);
+        storage::set_escrow_contract(&env, &escrow_contract);
+    }
+
+    /// Get the contract admin.
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        storage::get_admin(&env).ok_or(Error::NotInitialized)
+    }
+
+    /// Get the escrow contract disputes are raised against.
+    pub fn get_escrow_contract(env: Env) -> Result<Address, Error> {
+        storage::get_escrow_contract(&env).ok_or(Error::NotInitialized)
+    }
+
+    /// Raise a new dispute against a split using the default resolution policy.
+    ///
+    /// `category` tags the dispute for reporting and must be one of `payment`, `fraud`,
+    /// `miscomm` or `other`. `target` names the participant the dispute is about, or
+    /// `None` for the split as a whole; it must be a participant of the split.
     pub fn raise_dispute(
         env: Env,
         split_id: String,
         raiser: Address,
         reason: String,
+        category: Symbol,
+        target: Option<Address>,
     ) -> Result<String, Error> {
-        raiser.require_auth();
-
-        let now = env.ledger().timestamp();
-        let dispute_id = generate_dispute_id(&env, &split_id);
-
-        if storage::has_dispute(&env, &dispute_id) {
-            return Err(Error::AlreadyExists);
-        }
-
-        let dispute = Dispute {
-            dispute_id: dispute_id.clone(),
+        open_dispute(
+            &env,
             split_id,
             raiser,
             reason,
-            status: DisputeStatus::Voting,
-            votes_for: 0,
-            votes_against: 0,
-            voters: soroban_sdk::Vec::new(&env),
-            created_at: now,
-            voting_ends_at: now + VOTING_PERIOD,
-            result: None,
-        };
-
-        storage::save_dispute(&env, &dispute);
-        storage::add_to_list(&env, dispute_id.clone());
+            category,
+            target,
+            default_policy(),
+            false,
+        )
+    }
 
-        Ok(dispute_id)
+    /// Raise a new dispute that resolves under its own quorum, supermajority and voting period.
+    ///
+    /// `supermajority_ratio` is in basis points and must be at least a simple majority (5000)
+    /// and below 10000; `voting_period` must be between one day and one year, and at least
+    /// one of `min_votes` or `min_distinct_voters` must be non-zero, so a dispute nobody
+    /// votes on ends in `NoQuorum` rather than `tie_break`. `tie_break` is the result
+    /// returned when votes are equal. With `decay_enabled`, each vote
+    /// counts in proportion to how much of the voting window is left when it is cast,
+    /// but never less than 1.
+    /// Split participants may keep voting for `participant_grace_secs` after the window
+    /// closes, checked against the escrow contract; resolution waits for the grace to end.
+    // Flat arguments keep this a superset of `raise_dispute` for clients
+    #[allow(clippy::too_many_arguments)]
+    pub fn raise_dispute_with_policy(
+        env: Env,
+        split_id: String,
+        raiser: Address,
+        reason: String,
+        category: Symbol,
+        target: Option<Address>,
+        policy: ResolutionPolicy,
+        decay_enabled: bool,
+    ) -> Result<String, Error> {
+        validate_policy(&policy)?;
+        open_dispute(
+            &env,
+            split_id,
+            raiser,
+            reason,
+            category,
+            target,
+            policy,
+            decay_enabled,
+        )
     }
 
     /// Cast a vote on an open dispute.
@@ -85,8 +484,8 @@ impl DisputeContract {
 
         let now = env.ledger().timestamp();
 
-        // Voting window must still be open
-        if now > dispute.voting_ends_at {
+        // Voting window must still be open; split participants get the grace period too
+        if now > dispute.voting_ends_at && !in_participant_grace(&env, &dispute, &voter, now) {
             return Err(Error::VotingPeriodEnded);
         }
 
@@ -95,58 +494,481 @@ impl DisputeContract {
             return Err(Error::AlreadyVoted);
         }
 
-        // Record the vote
-        if support {
-            dispute.votes_for += 1;
+        apply_vote(&env, &mut dispute, &voter, support, now);
+        storage::save_dispute(&env, &dispute);
+
+        Ok(())
+    }
+
+    /// Record votes collected off-chain, submitted by the configured relayer.
+    ///
+    /// Voters who have already voted are skipped rather than failing the batch.
+    /// Returns how many votes were newly recorded.
+    pub fn batch_vote(
+        env: Env,
+        dispute_id: String,
+        relayer: Address,
+        votes: soroban_sdk::Vec<(Address, bool)>,
+    ) -> Result<u32, Error> {
+        relayer.require_auth();
+
+        if storage::get_relayer(&env) != Some(relayer) {
+            return Err(Error::NotAuthorized);
+        }
+
+        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        if dispute.status != DisputeStatus::Voting {
+            return Err(Error::DisputeClosed);
+        }
+
+        let now = env.ledger().timestamp();
+        if now > dispute.voting_ends_at {
+            return Err(Error::VotingPeriodEnded);
+        }
+
+        let mut recorded = 0;
+        for (voter, support) in votes.iter() {
+            if storage::has_voted(&env, &dispute_id, &voter) {
+                continue;
+            }
+            apply_vote(&env, &mut dispute, &voter, support, now);
+            recorded += 1;
+        }
+        storage::save_dispute(&env, &dispute);
+
+        Ok(recorded)
+    }
+
+    /// Set the relayer allowed to submit off-chain ballots through `batch_vote`.
+    pub fn set_relayer(env: Env, relayer: Address) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotAuthorized)?;
+        admin.require_auth();
+
+        storage::set_relayer(&env, &relayer);
+        Ok(())
+    }
+
+    /// Switch a previously cast vote to the other side while voting is open.
+    ///
+    /// The vote keeps its original weight.
+    pub fn change_vote(
+        env: Env,
+        dispute_id: String,
+        voter: Address,
+        support: bool,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+
+        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        if dispute.status != DisputeStatus::Voting {
+            return Err(Error::DisputeClosed);
+        }
+
+        let now = env.ledger().timestamp();
+
+        if now > dispute.voting_ends_at {
+            return Err(Error::VotingPeriodEnded);
+        }
+
+        // An unmigrated legacy record is a bare bool and won't decode as a Vote
+        if storage::is_legacy_vote(&env, &dispute_id, &voter) {
+            return Err(Error::VoteDirectionUnknown);
+        }
+
+        let mut vote = storage::get_vote(&env, &dispute_id, &voter).ok_or(Error::NotVoted)?;
+
+        // The tally can't be moved for a vote whose side was never recorded
+        if is_unknown_vote(&vote) {
+            return Err(Error::VoteDirectionUnknown);
+        }
+
+        if now < vote.cast_at.saturating_add(dispute.vote_cooldown_secs) {
+            return Err(Error::VoteCooldown);
+        }
+
+        if vote.support != support {
+            if support {
+                dispute.votes_against -= vote.weight;
+                dispute.votes_for += vote.weight;
+            } else {
+                dispute.votes_for -= vote.weight;
+                dispute.votes_against += vote.weight;
+            }
+        }
+
+        vote.support = support;
+        vote.cast_at = now;
+        storage::record_vote(&env, &dispute_id, &voter, &vote);
+        storage::save_dispute(&env, &dispute);
+
+        events::emit_vote_cast(
+            &env,
+            &dispute_id,
+            &voter,
+            support,
+            dispute.votes_for,
+            dispute.votes_against,
+        );
+
+        Ok(())
+    }
+
+    /// Set the minimum seconds between a vote and changing or revoking it.
+    ///
+    /// Admin only. Rate-limits vote churn; first votes are unaffected.
+    pub fn set_vote_cooldown(
+        env: Env,
+        dispute_id: String,
+        cooldown_secs: u64,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotAuthorized)?;
+        admin.require_auth();
+
+        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+        dispute.vote_cooldown_secs = cooldown_secs;
+        storage::save_dispute(&env, &dispute);
+
+        Ok(())
+    }
+
+    /// Vote on a dispute with a one-line rationale attached.
+    pub fn vote_with_memo(
+        env: Env,
+        dispute_id: String,
+        voter: Address,
+        support: bool,
+        memo: String,
+    ) -> Result<(), Error> {
+        if memo.len() > MAX_MEMO_LEN {
+            return Err(Error::MemoTooLong);
+        }
+
+        Self::vote_on_dispute(env.clone(), dispute_id.clone(), voter.clone(), support)?;
+        storage::set_vote_memo(&env, &dispute_id, &voter, &memo);
+
+        Ok(())
+    }
+
+    /// Withdraw a previously cast vote while voting is still open.
+    pub fn revoke_vote(
+        env: Env,
+        dispute_id: String,
+        voter: Address,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+
+        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        if dispute.status != DisputeStatus::Voting {
+            return Err(Error::DisputeClosed);
+        }
+
+        if env.ledger().timestamp() > dispute.voting_ends_at {
+            return Err(Error::VotingPeriodEnded);
+        }
+
+        // An unmigrated legacy record is a bare bool and won't decode as a Vote
+        if storage::is_legacy_vote(&env, &dispute_id, &voter) {
+            return Err(Error::VoteDirectionUnknown);
+        }
+
+        let vote = storage::get_vote(&env, &dispute_id, &voter).ok_or(Error::NotVoted)?;
+
+        // The tally can't be moved for a vote whose side was never recorded
+        if is_unknown_vote(&vote) {
+            return Err(Error::VoteDirectionUnknown);
+        }
+
+        if env.ledger().timestamp() < vote.cast_at.saturating_add(dispute.vote_cooldown_secs) {
+            return Err(Error::VoteCooldown);
+        }
+
+        // Take back exactly what the vote added
+        if vote.support {
+            dispute.votes_for -= vote.weight;
         } else {
-            dispute.votes_against += 1;
+            dispute.votes_against -= vote.weight;
         }
 
-        dispute.voters.push_back(voter.clone());
-        storage::record_vote(&env, &dispute_id, &voter);
+        if let Some(index) = dispute.voters.first_index_of(&voter) {
+            dispute.voters.remove(index);
+        }
+
+        storage::remove_vote(&env, &dispute_id, &voter);
         storage::save_dispute(&env, &dispute);
 
+        events::emit_vote_revoked(
+            &env,
+            &dispute_id,
+            &voter,
+            dispute.votes_for,
+            dispute.votes_against,
+        );
+
+        Ok(())
+    }
+
+    /// Attach a short note to a dispute's public comment log.
+    ///
+    /// Only the raiser, voters on the dispute and participants of the split may comment,
+    /// each at most `MAX_COMMENTS_PER_AUTHOR` times.
+    pub fn add_comment(
+        env: Env,
+        dispute_id: String,
+        author: Address,
+        text: String,
+    ) -> Result<(), Error> {
+        author.require_auth();
+
+        let dispute = storage::get_dispute(&env, &dispute_id)?;
+        if !is_dispute_party(&env, &dispute, &author) {
+            return Err(Error::NotAuthorized);
+        }
+
+        if text.len() > MAX_COMMENT_LEN {
+            return Err(Error::CommentTooLong);
+        }
+
+        let mut comments = storage::get_comments(&env, &dispute_id);
+        let by_author = comments.iter().filter(|c| c.author == author).count() as u32;
+        if comments.len() >= MAX_COMMENTS || by_author >= MAX_COMMENTS_PER_AUTHOR {
+            return Err(Error::TooManyComments);
+        }
+
+        comments.push_back(Comment {
+            author,
+            timestamp: env.ledger().timestamp(),
+            text,
+        });
+        storage::set_comments(&env, &dispute_id, &comments);
+
         Ok(())
     }
 
     /// Resolve a dispute after voting period ends.
+    ///
+    /// Anyone may call this, and Soroban doesn't expose the invoker, so
+    /// `resolved_by` stays `None`; use `resolve_dispute_as` to record it.
     pub fn resolve_dispute(
         env: Env,
         dispute_id: String,
     ) -> Result<DisputeResult, Error> {
-        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+        finalize_dispute(&env, &dispute_id, None)
+    }
+
+    /// Resolve a dispute before voting closes once one side holds a clear lead.
+    ///
+    /// Requires the policy's `early_ratio` for either side and at least
+    /// `min_votes_for_early` distinct voters, so a handful of early votes
+    /// can't close a dispute on their own.
+    pub fn resolve_dispute_early(
+        env: Env,
+        dispute_id: String,
+    ) -> Result<DisputeResult, Error> {
+        let dispute = storage::get_dispute(&env, &dispute_id)?;
 
         if dispute.status != DisputeStatus::Voting {
             return Err(Error::DisputeClosed);
         }
 
-        let now = env.ledger().timestamp();
+        let result = early_result(&dispute).ok_or(Error::EarlyResolutionNotMet)?;
+
+        Ok(close_dispute(&env, dispute, result, None))
+    }
+
+    /// Resolve a dispute after voting period ends, recording who resolved it.
+    pub fn resolve_dispute_as(
+        env: Env,
+        dispute_id: String,
+        resolver: Address,
+    ) -> Result<DisputeResult, Error> {
+        resolver.require_auth();
+        finalize_dispute(&env, &dispute_id, Some(resolver))
+    }
+
+    /// Override a tied result with an admin decision.
+    ///
+    /// Only allowed once the dispute has resolved as `Tied`, and only to
+    /// `UpheldForRaiser` or `DismissedForRaiser`.
+    pub fn break_tie(
+        env: Env,
+        dispute_id: String,
+        decision: DisputeResult,
+    ) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotAuthorized)?;
+        admin.require_auth();
 
-        // Voting period must have ended
-        if now <= dispute.voting_ends_at {
-            return Err(Error::VotingPeriodActive);
+        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        if dispute.status != DisputeStatus::Resolved || dispute.result != DisputeResult::Tied {
+            return Err(Error::NotTied);
         }
 
-        // Determine result based on votes
-        let result = if dispute.votes_for > dispute.votes_against {
-            DisputeResult::UpheldForRaiser
-        } else if dispute.votes_against > dispute.votes_for {
-            DisputeResult::DismissedForRaiser
-        } else {
-            DisputeResult::Tied
-        };
+        if decision == DisputeResult::Tied
+            || decision == DisputeResult::NoQuorum
+            || decision == DisputeResult::Pending
+        {
+            return Err(Error::InvalidDecision);
+        }
+
+        dispute.result = decision.clone();
+        dispute.resolved_at = Some(env.ledger().timestamp());
+        dispute.resolved_by = Some(admin.clone());
+        storage::save_dispute(&env, &dispute);
+
+        events::emit_tie_broken(&env, &dispute_id, &admin, &decision);
+
+        Ok(())
+    }
+
+    /// Set how long after resolution the admin may reopen a dispute.
+    pub fn set_reopen_window(env: Env, window: u64) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotAuthorized)?;
+        admin.require_auth();
+
+        storage::set_reopen_window(&env, window);
+        Ok(())
+    }
+
+    /// Require raisers to be participants of the split, checked against the escrow contract.
+    pub fn set_require_participant(env: Env, required: bool) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotAuthorized)?;
+        admin.require_auth();
+
+        storage::set_require_participant(&env, required);
+        Ok(())
+    }
+
+    /// Rewrite a dispute's legacy voter records into the `Vote` format.
+    ///
+    /// Admin only. Records written
+    /// before votes stored their direction hold only `true`, so which side
+    /// they took can't be recovered: they're migrated with no weight and an
+    /// unknown direction, the tally already counts them, and they can't be
+    /// changed or revoked. Safe to run more than once. Returns how many
+    /// records were migrated.
+    pub fn migrate_voter_records(env: Env, dispute_id: String) -> Result<u32, Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotAuthorized)?;
+        admin.require_auth();
+
+        let dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        let mut migrated = 0;
+        for voter in dispute.voters.iter() {
+            if storage::is_legacy_vote(&env, &dispute_id, &voter) {
+                storage::record_vote(
+                    &env,
+                    &dispute_id,
+                    &voter,
+                    &Vote { support: false, weight: 0, cast_at: UNKNOWN_VOTE_CAST_AT },
+                );
+                migrated += 1;
+            }
+        }
+
+        Ok(migrated)
+    }
 
-        dispute.status = DisputeStatus::Resolved;
-        dispute.result = Some(result.clone());
+    /// Get which side a voter took, or `None` for a migrated vote of unknown direction.
+    pub fn get_vote_direction(
+        env: Env,
+        dispute_id: String,
+        voter: Address,
+    ) -> Result<Option<bool>, Error> {
+        if storage::is_legacy_vote(&env, &dispute_id, &voter) {
+            return Ok(None);
+        }
+        let vote = storage::get_vote(&env, &dispute_id, &voter).ok_or(Error::NotVoted)?;
+        if is_unknown_vote(&vote) {
+            return Ok(None);
+        }
+        Ok(Some(vote.support))
+    }
+
+    /// Reopen a resolved dispute for a fresh voting window.
+    ///
+    /// Admin only, and only within the reopen window after resolution.
+    /// With `reset_votes` the previous tally and voters are cleared;
+    /// otherwise they carry over and only new voters may vote.
+    pub fn reopen_dispute(
+        env: Env,
+        dispute_id: String,
+        admin: Address,
+        reset_votes: bool,
+    ) -> Result<(), Error> {
+        if storage::get_admin(&env) != Some(admin.clone()) {
+            return Err(Error::NotAuthorized);
+        }
+        admin.require_auth();
+
+        let mut dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        if dispute.status != DisputeStatus::Resolved {
+            return Err(Error::NotResolved);
+        }
+
+        let now = env.ledger().timestamp();
+        let window = storage::get_reopen_window(&env, DEFAULT_REOPEN_WINDOW);
+        let resolved_at = dispute.resolved_at.unwrap_or(0);
+        if now > resolved_at.saturating_add(window) {
+            return Err(Error::ReopenWindowClosed);
+        }
+
+        if reset_votes {
+            for voter in dispute.voters.iter() {
+                storage::remove_vote(&env, &dispute_id, &voter);
+            }
+            dispute.votes_for = 0;
+            dispute.votes_against = 0;
+            dispute.voters = soroban_sdk::Vec::new(&env);
+        }
+
+        dispute.status = DisputeStatus::Voting;
+        dispute.result = DisputeResult::Pending;
+        dispute.resolved_at = None;
+        dispute.resolved_by = None;
+        dispute.voting_ends_at = now
+            .checked_add(dispute.resolution_policy.voting_period)
+            .ok_or(Error::InvalidPolicy)?;
 
         storage::save_dispute(&env, &dispute);
+        storage::set_active_count(&env, storage::get_active_count(&env) + 1);
+
+        events::emit_dispute_reopened(&env, &dispute_id, &admin, dispute.voting_ends_at);
+
+        Ok(())
+    }
 
-        // TODO: trigger payout logic based on result
-        // if result == DisputeResult::UpheldForRaiser {
-        //     split_client.reverse_split(&dispute.split_id);
-        // }
+    /// Resolve every expired dispute in a page of the dispute list.
+    ///
+    /// Intended for keepers: scans `limit` entries starting at `start` and
+    /// resolves each one still in `Voting` whose window has closed.
+    /// Returns how many disputes were resolved.
+    pub fn resolve_expired(env: Env, start: u32, limit: u32) -> u32 {
+        let list = storage::get_list(&env);
+        let now = env.ledger().timestamp();
+        let end = start.saturating_add(limit).min(list.len());
+
+        let mut resolved = 0;
+        for i in start..end {
+            let dispute_id = list.get(i).unwrap();
+            let dispute = match storage::get_dispute(&env, &dispute_id) {
+                Ok(d) => d,
+                Err(_) => continue,
+            };
+
+            if dispute.status != DisputeStatus::Voting || now <= voting_closes_at(&dispute) {
+                continue;
+            }
+
+            let result = tally_result(&dispute);
+            close_dispute(&env, dispute, result, None);
+            resolved += 1;
+        }
 
-        Ok(result)
+        resolved
     }
 
     /// Get a dispute record.
@@ -157,8 +979,75 @@ impl DisputeContract {
         storage::get_dispute(&env, &dispute_id)
     }
 
+    /// Get the current vote tally as `(votes_for, votes_against, voting_ends_at)`.
+    ///
+    /// Cheaper than `get_dispute` for UIs polling a live vote.
+    pub fn get_vote_tally(
+        env: Env,
+        dispute_id: String,
+    ) -> Result<(u32, u32, u64), Error> {
+        let dispute = storage::get_dispute(&env, &dispute_id)?;
+        Ok((dispute.votes_for, dispute.votes_against, dispute.voting_ends_at))
+    }
+
+    /// Check whether an address has a vote recorded on a dispute.
+    pub fn has_voted_on(env: Env, dispute_id: String, voter: Address) -> bool {
+        storage::has_voted(&env, &dispute_id, &voter)
+    }
+
+    /// Get how many disputes are currently in Voting.
+    pub fn active_dispute_count(env: Env) -> u32 {
+        storage::get_active_count(&env)
+    }
+
+    /// Get the seconds left in a dispute's voting window, or 0 once it has ended.
+    pub fn get_remaining_voting_time(
+        env: Env,
+        dispute_id: String,
+    ) -> Result<u64, Error> {
+        let dispute = storage::get_dispute(&env, &dispute_id)?;
+
+        if dispute.status != DisputeStatus::Voting {
+            return Err(Error::DisputeClosed);
+        }
+
+        Ok(dispute.voting_ends_at.saturating_sub(env.ledger().timestamp()))
+    }
+
+    /// Get a dispute's comments, oldest first.
+    pub fn get_comments(
+        env: Env,
+        dispute_id: String,
+    ) -> Result<soroban_sdk::Vec<Comment>, Error> {
+        if !storage::has_dispute(&env, &dispute_id) {
+            return Err(Error::NotFound);
+        }
+        Ok(storage::get_comments(&env, &dispute_id))
+    }
+
+    /// Whether a dispute's result should trigger a payout reversal.
+    pub fn get_resolution_action(env: Env, dispute_id: String) -> Result<bool, Error> {
+        let dispute = storage::get_dispute(&env, &dispute_id)?;
+        Ok(triggers_payout(&dispute))
+    }
+
+    /// Get the memo a voter attached to their vote, if any.
+    pub fn get_vote_memo(env: Env, dispute_id: String, voter: Address) -> Option<String> {
+        storage::get_vote_memo(&env, &dispute_id, &voter)
+    }
+
     /// Get all dispute IDs.
     pub fn get_all_disputes(env: Env) -> soroban_sdk::Vec<String> {
         storage::get_list(&env)
     }
+
+    /// Get the IDs of every dispute raised by an address.
+    pub fn get_disputes_by_raiser(env: Env, raiser: Address) -> soroban_sdk::Vec<String> {
+        storage::get_raiser_index(&env, &raiser)
+    }
+
+    /// Get the IDs of every dispute tagged with a category.
+    pub fn get_disputes_by_category(env: Env, category: Symbol) -> soroban_sdk::Vec<String> {
+        storage::get_category_index(&env, &category)
+    }
 }
\ No newline at end of file
@@ -1,18 +1,102 @@
-use soroban_sdk::{Env, String, Address, Vec};
-use crate::types::{DataKey, Dispute};
+use soroban_sdk::{Env, String, Address, Map, Symbol, TryFromVal, Val, Vec};
+use crate::types::{Comment, DataKey, Dispute, DisputeResult, LegacyDispute, Vote};
 use crate::errors::Error;
 
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_escrow_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::EscrowContract)
+}
+
+pub fn set_escrow_contract(env: &Env, escrow_contract: &Address) {
+    env.storage().instance().set(&DataKey::EscrowContract, escrow_contract);
+}
+
+pub fn get_reopen_window(env: &Env, default: u64) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReopenWindow)
+        .unwrap_or(default)
+}
+
+pub fn set_reopen_window(env: &Env, window: u64) {
+    env.storage().instance().set(&DataKey::ReopenWindow, &window);
+}
+
+pub fn get_require_participant(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireParticipant)
+        .unwrap_or(false)
+}
+
+pub fn set_require_participant(env: &Env, required: bool) {
+    env.storage().instance().set(&DataKey::RequireParticipant, &required);
+}
+
+pub fn get_relayer(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Relayer)
+}
+
+pub fn set_relayer(env: &Env, relayer: &Address) {
+    env.storage().instance().set(&DataKey::Relayer, relayer);
+}
+
 pub fn save_dispute(env: &Env, dispute: &Dispute) {
     env.storage()
         .persistent()
         .set(&DataKey::Dispute(dispute.dispute_id.clone()), dispute);
 }
 
+/// Load a dispute, upgrading one still stored in the `LegacyDispute` layout.
 pub fn get_dispute(env: &Env, dispute_id: &String) -> Result<Dispute, Error> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .persistent()
         .get(&DataKey::Dispute(dispute_id.clone()))
-        .ok_or(Error::NotFound)
+        .ok_or(Error::NotFound)?;
+    // Check the shape first: decoding a legacy record as a `Dispute` would trap
+    if let Some(legacy) = decode_legacy_dispute(env, &raw) {
+        return Ok(crate::upgrade_dispute(env, legacy));
+    }
+    Dispute::try_from_val(env, &raw).map_err(|_| Error::NotFound)
+}
+
+/// A stored dispute in the `LegacyDispute` layout, if it is one.
+fn decode_legacy_dispute(env: &Env, raw: &Val) -> Option<LegacyDispute> {
+    let fields = Map::<Symbol, Val>::try_from_val(env, raw).ok()?;
+    if fields.contains_key(Symbol::new(env, "resolution_policy")) {
+        return None;
+    }
+    Some(LegacyDispute {
+        dispute_id: legacy_field(env, &fields, "dispute_id")?,
+        split_id: legacy_field(env, &fields, "split_id")?,
+        raiser: legacy_field(env, &fields, "raiser")?,
+        reason: legacy_field(env, &fields, "reason")?,
+        status: legacy_field(env, &fields, "status")?,
+        votes_for: legacy_field(env, &fields, "votes_for")?,
+        votes_against: legacy_field(env, &fields, "votes_against")?,
+        voters: legacy_field(env, &fields, "voters")?,
+        created_at: legacy_field(env, &fields, "created_at")?,
+        voting_ends_at: legacy_field(env, &fields, "voting_ends_at")?,
+        result: legacy_field::<Option<DisputeResult>>(env, &fields, "result")?,
+    })
+}
+
+/// Decode one field of a legacy dispute record.
+fn legacy_field<T: TryFromVal<Env, Val>>(env: &Env, fields: &Map<Symbol, Val>, name: &str) -> Option<T> {
+    let value = fields.get(Symbol::new(env, name))?;
+    T::try_from_val(env, &value).ok()
 }
 
 pub fn has_dispute(env: &Env, dispute_id: &String) -> bool {
@@ -40,14 +124,109 @@ pub fn get_list(env: &Env) -> Vec<String> {
         .unwrap_or(Vec::new(env))
 }
 
+pub fn get_active_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActiveCount)
+        .unwrap_or(0)
+}
+
+pub fn set_active_count(env: &Env, count: u32) {
+    env.storage().persistent().set(&DataKey::ActiveCount, &count);
+}
+
+pub fn add_to_raiser_index(env: &Env, raiser: &Address, dispute_id: String) {
+    let key = DataKey::RaiserIndex(raiser.clone());
+    let mut list: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    list.push_back(dispute_id);
+    env.storage().persistent().set(&key, &list);
+}
+
+pub fn get_raiser_index(env: &Env, raiser: &Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RaiserIndex(raiser.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_category_index(env: &Env, category: &Symbol, dispute_id: String) {
+    let key = DataKey::CategoryIndex(category.clone());
+    let mut list: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    list.push_back(dispute_id);
+    env.storage().persistent().set(&key, &list);
+}
+
+pub fn get_category_index(env: &Env, category: &Symbol) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryIndex(category.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
 pub fn has_voted(env: &Env, dispute_id: &String, voter: &Address) -> bool {
     env.storage()
         .persistent()
         .has(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()))
 }
 
-pub fn record_vote(env: &Env, dispute_id: &String, voter: &Address) {
+pub fn record_vote(env: &Env, dispute_id: &String, voter: &Address, vote: &Vote) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()), vote);
+}
+
+pub fn get_vote(env: &Env, dispute_id: &String, voter: &Address) -> Option<Vote> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()))
+}
+
+/// Whether a voter record predates `Vote` and was stored as a bare `true`.
+pub fn is_legacy_vote(env: &Env, dispute_id: &String, voter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Val>(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()))
+        .is_some_and(|raw| bool::try_from_val(env, &raw).is_ok())
+}
+
+pub fn remove_vote(env: &Env, dispute_id: &String, voter: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::VoteMemo(dispute_id.clone(), voter.clone()));
+}
+
+pub fn get_vote_memo(env: &Env, dispute_id: &String, voter: &Address) -> Option<String> {
     env.storage()
         .persistent()
-        .set(&DataKey::VoterRecord(dispute_id.clone(), voter.clone()), &true);
-}
\ No newline at end of file
+        .get(&DataKey::VoteMemo(dispute_id.clone(), voter.clone()))
+}
+
+pub fn set_vote_memo(env: &Env, dispute_id: &String, voter: &Address, memo: &String) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VoteMemo(dispute_id.clone(), voter.clone()), memo);
+}
+
+pub fn get_comments(env: &Env, dispute_id: &String) -> Vec<Comment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Comments(dispute_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_comments(env: &Env, dispute_id: &String, comments: &Vec<Comment>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Comments(dispute_id.clone()), comments);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, String, Address, Vec};
+use soroban_sdk::{contracttype, String, Address, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -15,6 +15,37 @@ pub enum DisputeResult {
     UpheldForRaiser,    // Dispute was valid, raiser wins
     DismissedForRaiser, // Dispute was invalid, original split stands
     Tied,               // Equal votes, default to original split
+    NoQuorum,           // Too few voters to decide; no action
+    Pending,            // Not resolved yet
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolutionPolicy {
+    pub min_votes: u32,           // quorum of total vote weight, for and against; below it NoQuorum
+    pub min_distinct_voters: u32, // quorum of distinct voters, checked alongside min_votes
+    pub supermajority_ratio: u32, // basis points of weighted votes that support must exceed
+    pub voting_period: u64,       // seconds
+    pub tie_break: DisputeResult, // result when votes are equal
+    pub participant_grace_secs: u64, // extra time split participants may still vote
+    pub min_votes_for_early: u32, // distinct voters needed before resolve_dispute_early
+    pub early_ratio: u32,         // basis points of weighted votes either side must exceed to close early
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vote {
+    pub support: bool,
+    pub weight: u32, // amount added to the tally, so it can be taken back out
+    pub cast_at: u64, // when the vote was last cast or changed
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment {
+    pub author: Address,
+    pub timestamp: u64,
+    pub text: String,
 }
 
 #[contracttype]
@@ -24,18 +55,54 @@ pub struct Dispute {
     pub split_id: String,
     pub raiser: Address,
     pub reason: String,
+    pub category: Symbol,    // payment, fraud, miscomm or other
+    pub target: Option<Address>, // participant the dispute is about; None for the whole split
     pub status: DisputeStatus,
-    pub votes_for: u32,      // votes supporting the dispute
-    pub votes_against: u32,  // votes dismissing the dispute
+    pub votes_for: u32,      // votes (or decayed weight) supporting the dispute
+    pub votes_against: u32,  // votes (or decayed weight) dismissing the dispute
     pub voters: Vec<Address>,
     pub created_at: u64,
     pub voting_ends_at: u64, // voting window: 7 days
+    pub result: DisputeResult, // Pending until resolved
+    pub resolved_at: Option<u64>,
+    pub resolved_by: Option<Address>, // None when resolved permissionlessly
+    pub resolution_policy: ResolutionPolicy,
+    pub decay_enabled: bool, // scale each vote by the share of the window remaining
+    pub vote_cooldown_secs: u64, // minimum gap before a vote may be changed or revoked
+}
+
+/// A dispute as stored before category, target, resolution and policy fields were added.
+///
+/// Not a `contracttype`: its optional result can't be converted under testutils,
+/// so `storage::get_dispute` decodes it field by field instead.
+#[derive(Clone, Debug)]
+pub struct LegacyDispute {
+    pub dispute_id: String,
+    pub split_id: String,
+    pub raiser: Address,
+    pub reason: String,
+    pub status: DisputeStatus,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    pub voters: Vec<Address>,
+    pub created_at: u64,
+    pub voting_ends_at: u64,
     pub result: Option<DisputeResult>,
 }
 
 #[contracttype]
 pub enum DataKey {
+    Admin,
+    EscrowContract,
+    ReopenWindow,                 // seconds after resolution a dispute may be reopened
+    RequireParticipant,           // only escrow participants may raise disputes
+    Relayer,                      // may submit off-chain ballots in bulk
     Dispute(String),
     DisputeList,
-    VoterRecord(String, Address), // (dispute_id, voter) -> bool (has voted)
+    ActiveCount,                  // number of disputes in Voting
+    RaiserIndex(Address),         // raiser -> Vec<dispute_id>
+    CategoryIndex(Symbol),        // category -> Vec<dispute_id>
+    VoterRecord(String, Address), // (dispute_id, voter) -> Vote
+    VoteMemo(String, Address),    // (dispute_id, voter) -> String
+    Comments(String),             // dispute_id -> Vec<Comment>
 }
\ No newline at end of file
@@ -3,8 +3,9 @@
 //! I'm defining all contract events here for off-chain tracking and indexing.
 //! These events are crucial for the backend to sync with on-chain state.
 
-use soroban_sdk::{symbol_short, Address, Env, String};
-use soroban_sdk::contractevent;
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::types::{EscrowStatus, SplitStatus};
 
 /// Emit when the contract is initialized
 ///
@@ -26,6 +27,98 @@ pub fn emit_split_created(env: &Env, split_id: u64, creator: &Address, total_amo
     );
 }
 
+/// Emit when a split moves from one status to another
+///
+/// I'm carrying the previous status so indexers can rebuild a split's
+/// lifecycle from events alone.
+pub fn emit_status_changed(env: &Env, split_id: u64, from: SplitStatus, to: SplitStatus) {
+    env.events().publish(
+        (Symbol::new(env, "status_changed"),),
+        (split_id, from, to, env.ledger().timestamp()),
+    );
+}
+
+/// Emit when a hashed-ID escrow moves from one status to another
+pub fn emit_escrow_status_changed(env: &Env, split_id: &String, from: EscrowStatus, to: EscrowStatus) {
+    env.events().publish(
+        (Symbol::new(env, "status_changed"),),
+        (split_id.clone(), from, to, env.ledger().timestamp()),
+    );
+}
+
+/// Emit when a new hashed-ID escrow is created
+///
+/// I'm mirroring `emit_split_created` for the string-keyed escrow flow.
+pub fn emit_escrow_created(env: &Env, split_id: &String, creator: &Address, total_amount: i128) {
+    env.events().publish(
+        (symbol_short!("esc_new"),),
+        (split_id.clone(), creator.clone(), total_amount),
+    );
+}
+
+/// Emit when a deposit is received into a hashed-ID escrow
+pub fn emit_escrow_deposit(env: &Env, split_id: &String, participant: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("esc_dep"),),
+        (split_id.clone(), participant.clone(), amount),
+    );
+}
+
+/// Emit when a participant withdraws their deposit before completion
+pub fn emit_escrow_withdrawal(env: &Env, split_id: &String, participant: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("esc_wdraw"),),
+        (split_id.clone(), participant.clone(), amount),
+    );
+}
+
+/// Emit when a completed hashed-ID escrow pays out to its creator
+pub fn emit_escrow_released(env: &Env, split_id: &String, creator: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("esc_rel"),),
+        (split_id.clone(), creator.clone(), amount),
+    );
+}
+
+/// Emit when a participant is refunded from a cancelled or expired escrow
+pub fn emit_escrow_refund(env: &Env, split_id: &String, participant: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("esc_rfnd"),),
+        (split_id.clone(), participant.clone(), amount),
+    );
+}
+
+/// Emit when the admin force-refunds an escrow
+///
+/// I'm giving this its own topic so monitoring can alert on emergency actions.
+pub fn emit_emergency_refund(env: &Env, split_id: &String, admin: &Address, refunded: u32) {
+    env.events().publish(
+        (symbol_short!("emergency"),),
+        (split_id.clone(), admin.clone(), refunded),
+    );
+}
+
+/// Emit when an underfunded escrow is nearing its deadline
+///
+/// I'm including what's still owed so notifiers can word the reminder.
+pub fn emit_deadline_approaching(env: &Env, split_id: &String, deadline: u64, remaining_owed: i128) {
+    env.events().publish(
+        (Symbol::new(env, "deadline_approaching"),),
+        (split_id.clone(), deadline, remaining_owed),
+    );
+}
+
+/// Emit when an escrow passes its deadline without completing
+///
+/// I'm including the amount collected so indexers know how much is
+/// waiting to be refunded.
+pub fn emit_expired(env: &Env, split_id: &String, amount_collected: i128) {
+    env.events().publish(
+        (symbol_short!("expired"),),
+        (split_id.clone(), amount_collected),
+    );
+}
+
 /// Emit when a deposit is received
 ///
 /// I'm emitting this for each deposit so the backend can
@@ -54,6 +147,14 @@ pub fn emit_funds_released(
     );
 }
 
+/// Emit when a participant is paid their share of an early-payment bonus
+pub fn emit_bonus_paid(env: &Env, split_id: u64, participant: &Address, amount: i128) {
+    env.events().publish(
+        (symbol_short!("bonus"),),
+        (split_id, participant.clone(), amount),
+    );
+}
+
 /// Emit when escrow is completed (fully funded)
 pub fn emit_escrow_completed(env: &Env, split_id: u64, total_amount: i128) {
     env.events()
@@ -64,9 +165,9 @@ pub fn emit_escrow_completed(env: &Env, split_id: u64, total_amount: i128) {
 ///
 /// I'm emitting this so the backend can trigger refund processing
 /// for any participants who have already deposited.
-pub fn emit_split_cancelled(env: &Env, split_id: u64) {
+pub fn emit_split_cancelled(env: &Env, split_id: u64, reason: &String) {
     env.events()
-        .publish((symbol_short!("cancel"),), (split_id,));
+        .publish((symbol_short!("cancel"),), (split_id, reason.clone()));
 }
 
 /// Emit when a refund is processed
@@ -94,7 +195,7 @@ pub fn emit_insurance_purchased(
     coverage_amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("ins_purchased"),),
+        (Symbol::new(env, "ins_purchased"),),
         (
             insurance_id.clone(),
             split_id.clone(),
@@ -114,7 +215,7 @@ pub fn emit_claim_filed(
     claim_amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("claim_filed"),),
+        (Symbol::new(env, "claim_filed"),),
         (
             claim_id.clone(),
             insurance_id.clone(),
@@ -133,7 +234,7 @@ pub fn emit_claim_processed(
     payout_amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("claim_processed"),),
+        (Symbol::new(env, "claim_processed"),),
         (
             claim_id.clone(),
             insurance_id.clone(),
@@ -151,7 +252,7 @@ pub fn emit_payout_made(
     amount: i128,
 ) {
     env.events().publish(
-        (symbol_short!("payout_made"),),
+        (Symbol::new(env, "payout_made"),),
         (claim_id.clone(), recipient.clone(), amount),
     );
 }
@@ -163,7 +264,7 @@ pub fn emit_payout_made(
 pub fn emit_activity_tracked(env: &Env, user: &Address, activity_type: &str, split_id: u64, amount: i128) {
     env.events()
         .publish(
-            (symbol_short!("activity_tracked"),),
+            (Symbol::new(env, "activity_tracked"),),
             (user.clone(), activity_type, split_id, amount)
         );
 }
@@ -174,7 +275,7 @@ pub fn emit_activity_tracked(env: &Env, user: &Address, activity_type: &str, spl
 pub fn emit_rewards_calculated(env: &Env, user: &Address, total_rewards: i128, available_rewards: i128) {
     env.events()
         .publish(
-            (symbol_short!("rewards_calculated"),),
+            (Symbol::new(env, "rewards_calculated"),),
             (user.clone(), total_rewards, available_rewards)
         );
 }
@@ -185,7 +286,7 @@ pub fn emit_rewards_calculated(env: &Env, user: &Address, total_rewards: i128, a
 pub fn emit_rewards_claimed(env: &Env, user: &Address, amount_claimed: i128) {
     env.events()
         .publish(
-            (symbol_short!("rewards_claimed"),),
+            (Symbol::new(env, "rewards_claimed"),),
             (user.clone(), amount_claimed)
         );
 }
@@ -196,7 +297,7 @@ pub fn emit_rewards_claimed(env: &Env, user: &Address, amount_claimed: i128) {
 pub fn emit_verification_submitted(env: &Env, verification_id: &String, split_id: &String, requester: &Address) {
     env.events()
         .publish(
-            (symbol_short!("verification_submitted"),),
+            (Symbol::new(env, "verification_submitted"),),
             (verification_id.clone(), split_id.clone(), requester.clone())
         );
 }
@@ -207,7 +308,7 @@ pub fn emit_verification_submitted(env: &Env, verification_id: &String, split_id
 pub fn emit_verification_completed(env: &Env, verification_id: &String, verified: bool, verifier: &Address) {
     env.events()
         .publish(
-            (symbol_short!("verification_completed"),),
+            (Symbol::new(env, "verification_completed"),),
             (verification_id.clone(), verified, verifier.clone())
         );
 }
@@ -218,13 +319,7 @@ pub fn emit_verification_completed(env: &Env, verification_id: &String, verified
 pub fn emit_verification_status_changed(env: &Env, verification_id: &String, old_status: &str, new_status: &str) {
     env.events()
         .publish(
-            (symbol_short!("verification_status_changed"),),
+            (Symbol::new(env, "verification_status_changed"),),
             (verification_id.clone(), old_status, new_status)
         );
 }
-
-#[contractevent]
-pub fn escrow_created(split_id: String, creator: Address, total_amount: i128);
-
-#[contractevent]
-pub fn payment_received(split_id: String, participant: Address, amount: i128);
@@ -11,9 +11,10 @@
 
 #![no_std]
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Vec, token};
-use soroban_sdk::token::TokenClient;
-use std::string::ToString;
+use soroban_sdk::{
+    contract, contractimpl, token, Address, BytesN, Env, IntoVal, InvokeError, String, Symbol,
+    Val, Vec,
+};
 
 mod events;
 mod storage;
@@ -26,6 +27,21 @@ pub use events::*;
 pub use storage::*;
 pub use types::*;
 
+/// Longest description, in bytes, a split or escrow may carry
+const MAX_DESCRIPTION_LEN: u32 = 280;
+
+/// Most participants a split or escrow may have, so payouts stay iterable
+const MAX_ESCROW_PARTICIPANTS: u32 = 100;
+
+/// Default seconds before a deadline that a warning may be emitted (1 day)
+const DEFAULT_WARNING_WINDOW: u64 = 86_400;
+
+/// Most metadata entries an escrow may carry
+const MAX_METADATA_ENTRIES: u32 = 16;
+
+/// Most escrow IDs kept in a participant's index; older ones are dropped
+const MAX_PARTICIPANT_SPLITS: u32 = 100;
+
 /// The main Split Escrow contract
 ///
 /// I'm keeping the initial implementation minimal - just the structure and
@@ -59,10 +75,42 @@ impl SplitEscrowContract {
         events::emit_initialized(&env, &admin);
     }
 
+    /// Set the largest total amount `create_split` will accept
+    ///
+    /// I'm treating 0 as "no cap" so the guard stays opt-in. Only the admin
+    /// can change it.
+    pub fn set_max_total_amount(env: Env, max_total_amount: i128) -> Result<(), Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if max_total_amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        storage::set_max_total_amount(&env, max_total_amount);
+        Ok(())
+    }
+
     /// Create a new split with the specified participants and amounts
     ///
     /// I'm designing this to be called by the split creator who will also
     /// be responsible for distributing funds once everyone has paid.
+    ///
+    /// `completion_tolerance` lets the split complete once it is at most
+    /// that many stroops short, so rounding dust can't leave it stuck. Pass
+    /// 0 to require the full total.
+    ///
+    /// `payout_recipient`, when set, receives released funds instead of the
+    /// creator; it can be changed later with `set_payout_recipient`.
+    ///
+    /// `idempotency_key` makes retries safe: a repeat call from the same
+    /// creator with the same key returns the split the first call created
+    /// instead of creating another.
+    ///
+    /// With `auto_release`, the deposit that completes the split also
+    /// releases it, subject to the same approvals as `release_funds`.
+    /// Otherwise the split waits in `Completed` for a `release_funds` call.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_split(
         env: Env,
         creator: Address,
@@ -70,10 +118,28 @@ impl SplitEscrowContract {
         total_amount: i128,
         participant_addresses: Vec<Address>,
         participant_shares: Vec<i128>,
-    ) -> u64 {
+        completion_tolerance: i128,
+        payout_recipient: Option<Address>,
+        idempotency_key: Option<BytesN<32>>,
+        auto_release: bool,
+    ) -> Result<u64, Error> {
         // Verify the creator is authorizing this call
         creator.require_auth();
 
+        if let Some(key) = &idempotency_key {
+            if let Some(split_id) = storage::get_idempotent_split(&env, &creator, key) {
+                return Ok(split_id);
+            }
+        }
+
+        Self::validate_description(&description)?;
+
+        // Guard against fat-fingered totals
+        let max_total_amount = storage::get_max_total_amount(&env);
+        if max_total_amount > 0 && total_amount > max_total_amount {
+            return Err(Error::AmountTooLarge);
+        }
+
         // Validate inputs
         if participant_addresses.len() != participant_shares.len() {
             panic!("Participant addresses and shares must have the same length");
@@ -83,6 +149,10 @@ impl SplitEscrowContract {
             panic!("At least one participant is required");
         }
 
+        if participant_addresses.len() > MAX_ESCROW_PARTICIPANTS {
+            return Err(Error::TooManyParticipants);
+        }
+
         // Validate shares sum to total
         let mut shares_sum: i128 = 0;
         for i in 0..participant_shares.len() {
@@ -92,6 +162,10 @@ impl SplitEscrowContract {
             panic!("Participant shares must sum to total amount");
         }
 
+        if completion_tolerance < 0 || completion_tolerance >= total_amount {
+            return Err(Error::InvalidAmount);
+        }
+
         // Get the next split ID
         let split_id = storage::get_next_split_id(&env);
 
@@ -103,6 +177,7 @@ impl SplitEscrowContract {
                 share_amount: participant_shares.get(i).unwrap(),
                 amount_paid: 0,
                 has_paid: false,
+                amount_released: 0,
             };
             participants.push_back(participant);
         }
@@ -118,28 +193,198 @@ impl SplitEscrowContract {
             participants,
             status: SplitStatus::Pending,
             created_at: env.ledger().timestamp(),
+            released: false,
+            completion_tolerance,
+            cancel_reason: None,
+            payout_recipient,
         };
 
         // Store the split
         storage::set_split(&env, split_id, &split);
 
+        if auto_release {
+            storage::set_auto_release(&env, split_id);
+        }
+
+        if let Some(key) = &idempotency_key {
+            storage::set_idempotent_split(&env, &creator, key, split_id);
+        }
+
         // Emit creation event
         events::emit_split_created(&env, split_id, &creator, total_amount);
 
-        split_id
+        Ok(split_id)
+    }
+
+    /// Create a new escrow keyed by a hashed, non-sequential ID
+    ///
+    /// I'm keeping `create_split` and its numeric IDs for back-compat. This
+    /// parallel path derives the ID from the creator, description, and ledger
+    /// sequence so IDs can't be guessed or used to count splits.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_split_hashed(
+        env: Env,
+        creator: Address,
+        description: String,
+        total_amount: i128,
+        participant_addresses: Vec<Address>,
+        participant_shares: Vec<i128>,
+        deadline: u64,
+        grace_period_secs: u64,
+    ) -> Result<String, Error> {
+        // Verify the creator is authorizing this call
+        creator.require_auth();
+
+        Self::store_hashed_escrow(
+            &env,
+            creator,
+            description,
+            total_amount,
+            participant_addresses,
+            participant_shares,
+            deadline,
+            grace_period_secs,
+            None,
+        )
+    }
+
+    /// Create a hashed-ID escrow whose total is shared equally
+    ///
+    /// I'm giving the stroops left over when `total_amount` doesn't divide
+    /// evenly to `rounding_beneficiary` (the first participant when `None`),
+    /// and storing that choice on the escrow so the rounding is explicit.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_equal_split_hashed(
+        env: Env,
+        creator: Address,
+        description: String,
+        total_amount: i128,
+        participant_addresses: Vec<Address>,
+        deadline: u64,
+        grace_period_secs: u64,
+        rounding_beneficiary: Option<Address>,
+    ) -> Result<String, Error> {
+        // Verify the creator is authorizing this call
+        creator.require_auth();
+
+        if participant_addresses.is_empty() {
+            panic!("At least one participant is required");
+        }
+
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let beneficiary = match &rounding_beneficiary {
+            Some(address) => {
+                if !participant_addresses.contains(address) {
+                    return Err(Error::ParticipantNotFound);
+                }
+                address.clone()
+            }
+            None => participant_addresses.get(0).unwrap(),
+        };
+
+        let count = participant_addresses.len() as i128;
+        let share = total_amount / count;
+        let remainder = total_amount - share * count;
+
+        let mut participant_shares = Vec::new(&env);
+        for address in participant_addresses.iter() {
+            if address == beneficiary {
+                participant_shares.push_back(share + remainder);
+            } else {
+                participant_shares.push_back(share);
+            }
+        }
+
+        Self::store_hashed_escrow(
+            &env,
+            creator,
+            description,
+            total_amount,
+            participant_addresses,
+            participant_shares,
+            deadline,
+            grace_period_secs,
+            rounding_beneficiary,
+        )
+    }
+
+    /// Create a fresh escrow with the same participants as an existing one
+    ///
+    /// I'm copying each participant's `amount_owed` (and the grace period)
+    /// into a new hashed-ID escrow with nothing collected, so recurring
+    /// expenses don't have to be rebuilt by hand. Source creator only.
+    pub fn clone_split(env: Env, source_split_id: String, new_deadline: u64) -> Result<String, Error> {
+        let source = storage::get_escrow(&env, &source_split_id).ok_or(Error::SplitNotFound)?;
+
+        source.creator.require_auth();
+
+        let split_id = storage::generate_hashed_escrow_id(&env, &source.creator, &source.description);
+        if storage::has_escrow(&env, &split_id) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        let mut participants = Vec::new(&env);
+        for p in source.participants.iter() {
+            participants.push_back(EscrowParticipant::new(p.address, p.amount_owed));
+        }
+
+        let mut escrow = create_escrow(
+            &env,
+            split_id.clone(),
+            source.creator.clone(),
+            source.description,
+            source.total_amount,
+            participants,
+            new_deadline,
+        );
+        escrow.grace_period_secs = source.grace_period_secs;
+        escrow.rounding_beneficiary = source.rounding_beneficiary;
+
+        storage::set_escrow(&env, &split_id, &escrow);
+        storage::increment_escrow_count(&env);
+        Self::index_participants(&env, &escrow);
+
+        let mut summary = storage::get_escrow_summary(&env);
+        summary.active += 1;
+        storage::set_escrow_summary(&env, &summary);
+
+        events::emit_escrow_created(&env, &split_id, &source.creator, source.total_amount);
+
+        Ok(split_id)
     }
 
     /// Deposit funds into a split
     ///
     /// I'm allowing partial deposits so participants can pay incrementally.
-    pub fn deposit(env: Env, split_id: u64, participant: Address, amount: i128) {
+    /// All accumulation uses checked arithmetic and returns `Error::Overflow`
+    /// rather than wrapping. Returns what the participant still owes after
+    /// this deposit (0 when fully paid) so wallets can skip a `get_split`.
+    ///
+    /// On an `auto_release` split, the deposit that completes it also
+    /// releases the funds, unless release approvals are still outstanding;
+    /// then `release_funds` pays out once they are in.
+    ///
+    /// When the admin has set deposit confirmations, the tokens are taken
+    /// now but held as pending until `confirm_deposit` settles them.
+    pub fn deposit(env: Env, split_id: u64, participant: Address, amount: i128) -> Result<i128, Error> {
         // Verify the participant is authorizing this call
         participant.require_auth();
 
+        if storage::get_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
         // Get the split
-        let mut split = storage::get_split(&env, split_id);
+        let split = storage::get_split(&env, split_id);
 
-        if amount <= 0 {
+        // A zero deposit would change nothing, including the Pending status
+        if amount == 0 {
+            return Err(Error::ZeroDeposit);
+        }
+        if amount < 0 {
             panic!("Deposit amount must be positive");
         }
 
@@ -148,147 +393,1133 @@ impl SplitEscrowContract {
             panic!("Split is not accepting deposits");
         }
 
-        // Find the participant in the split
+        // Deposits awaiting confirmation still count against what's owed
+        let pending = storage::get_pending_deposit(&env, split_id, &participant);
+        let pending_amount = pending.as_ref().map_or(0, |p| p.amount);
+
+        let remaining = match split.participants.iter().find(|p| p.address == participant) {
+            Some(p) => p
+                .share_amount
+                .checked_sub(p.amount_paid)
+                .and_then(|r| r.checked_sub(pending_amount))
+                .ok_or(Error::Overflow)?,
+            None => panic!("Participant not found in split"),
+        };
+        if amount > remaining {
+            panic!("Deposit exceeds remaining amount owed");
+        }
+
+        if storage::get_require_acceptance(&env)
+            && !storage::get_acceptances(&env, split_id).contains(&participant)
+        {
+            return Err(Error::NotAccepted);
+        }
+
+        split
+            .amount_collected
+            .checked_add(pending_amount)
+            .and_then(|c| c.checked_add(amount))
+            .ok_or(Error::Overflow)?;
+
+        // Transfer tokens from participant to escrow contract
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        token_client.transfer(&participant, &contract_address, &amount);
+        Self::lock_funds(&env, &token_address, amount)?;
+
+        let confirmations = storage::get_deposit_confirmations(&env);
+        if confirmations > 0 {
+            storage::set_pending_deposit(
+                &env,
+                split_id,
+                &participant,
+                &PendingDeposit {
+                    amount: pending_amount + amount,
+                    ready_at: env.ledger().sequence() + confirmations,
+                },
+            );
+            return Ok(remaining - amount);
+        }
+
+        Self::apply_deposit(&env, split_id, split, &participant, amount)
+    }
+
+    /// Set how many ledgers a deposit waits before it can be confirmed
+    ///
+    /// Admin only. 0, the default, counts deposits immediately.
+    pub fn set_deposit_confirmations(env: Env, confirmations: u32) {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        storage::set_deposit_confirmations(&env, confirmations);
+    }
+
+    /// Set whether participants must accept a split before depositing
+    ///
+    /// Admin only. Off by default, so existing splits keep working.
+    pub fn set_require_acceptance(env: Env, required: bool) {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        storage::set_require_acceptance(&env, required);
+    }
+
+    /// Pause or resume deposits into splits and escrows
+    ///
+    /// Admin only. Releases and refunds keep working while paused, so
+    /// funds already held can always leave.
+    pub fn set_paused(env: Env, paused: bool) {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        storage::set_paused(&env, paused);
+    }
+
+    /// Accept a split, consenting to owe the participant's share
+    ///
+    /// I'm recording acceptance even when it isn't required, so creators can
+    /// see who has agreed. Accepting twice is a no-op.
+    pub fn accept_split(env: Env, split_id: u64, participant: Address) -> Result<(), Error> {
+        participant.require_auth();
+
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        if split.status == SplitStatus::Cancelled {
+            return Err(Error::SplitCancelled);
+        }
+        if !split.participants.iter().any(|p| p.address == participant) {
+            return Err(Error::ParticipantNotFound);
+        }
+
+        let mut acceptances = storage::get_acceptances(&env, split_id);
+        if !acceptances.contains(&participant) {
+            acceptances.push_back(participant);
+            storage::set_acceptances(&env, split_id, &acceptances);
+        }
+
+        Ok(())
+    }
+
+    /// Get the participants who have accepted a split, in acceptance order
+    pub fn get_acceptances(env: Env, split_id: u64) -> Result<Vec<Address>, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        Ok(storage::get_acceptances(&env, split_id))
+    }
+
+    /// Count a participant's pending deposit toward their split
+    ///
+    /// Anyone can call this once the confirmation delay has passed, so a
+    /// keeper can settle deposits on participants' behalf. Returns what the
+    /// participant still owes, like `deposit`.
+    ///
+    /// If the split completed or released while the deposit waited (e.g.
+    /// within its completion tolerance), the deposit is refunded instead and
+    /// nothing more is owed.
+    pub fn confirm_deposit(env: Env, split_id: u64, participant: Address) -> Result<i128, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let pending = storage::get_pending_deposit(&env, split_id, &participant)
+            .ok_or(Error::NoPendingDeposit)?;
+        if env.ledger().sequence() < pending.ready_at {
+            return Err(Error::DepositNotConfirmed);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        storage::remove_pending_deposit(&env, split_id, &participant);
+
+        // The split no longer needs it, so hand it back rather than strand it
+        if split.status == SplitStatus::Completed || split.status == SplitStatus::Released {
+            let token_address = storage::get_token(&env);
+            let token_client = token::Client::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &participant, &pending.amount);
+            Self::unlock_funds(&env, &token_address, pending.amount);
+            return Ok(0);
+        }
+
+        Self::apply_deposit(&env, split_id, split, &participant, pending.amount)
+    }
+
+    /// Set an installment schedule for a participant of an escrow
+    ///
+    /// I'm letting the creator spread a participant's `amount_owed` over
+    /// `(due_timestamp, amount)` entries. Deposits can then only cover
+    /// installments that have already come due. The amounts must sum to
+    /// exactly what the participant owes.
+    pub fn set_installments(
+        env: Env,
+        split_id: String,
+        participant: Address,
+        schedule: Vec<(u64, i128)>,
+    ) -> Result<(), Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        // Only the creator can set a schedule
+        escrow.creator.require_auth();
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        let mut amount_owed = None;
+        for p in escrow.participants.iter() {
+            if p.address == participant {
+                amount_owed = Some(p.amount_owed);
+            }
+        }
+        let amount_owed = amount_owed.ok_or(Error::ParticipantNotFound)?;
+
+        let mut total: i128 = 0;
+        for (_, amount) in schedule.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+        }
+        if total != amount_owed {
+            return Err(Error::InvalidAmount);
+        }
+
+        storage::set_installments(&env, &split_id, &participant, &schedule);
+
+        Ok(())
+    }
+
+    /// Deposit funds into a hashed-ID escrow
+    ///
+    /// I'm enforcing the escrow deadline (plus any grace period) here, and if
+    /// the participant has an installment schedule, only installments already
+    /// due may be paid.
+    pub fn deposit_escrow(
+        env: Env,
+        split_id: String,
+        participant: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        // Verify the participant is authorizing this call
+        participant.require_auth();
+
+        if storage::get_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let now = env.ledger().timestamp();
+        if escrow.is_expired(now) {
+            return Err(Error::EscrowExpired);
+        }
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        // Find the participant and apply the deposit
         let mut found = false;
         let mut updated_participants = Vec::new(&env);
 
-        for i in 0..split.participants.len() {
-            let mut p = split.participants.get(i).unwrap();
+        for mut p in escrow.participants.iter() {
             if p.address == participant {
                 found = true;
-                let remaining = p.share_amount - p.amount_paid;
-                if amount > remaining {
-                    panic!("Deposit exceeds remaining amount owed");
+                if amount > p.remaining_owed() {
+                    return Err(Error::InvalidAmount);
                 }
 
-                p.amount_paid += amount;
-                p.has_paid = p.amount_paid >= p.share_amount;
+                let new_paid = p.amount_paid.checked_add(amount).ok_or(Error::Overflow)?;
+
+                // Only installments that have come due may be paid
+                let schedule = storage::get_installments(&env, &split_id, &participant);
+                if !schedule.is_empty() {
+                    let mut vested: i128 = 0;
+                    for (due, installment) in schedule.iter() {
+                        if due <= now {
+                            vested = vested.checked_add(installment).ok_or(Error::Overflow)?;
+                        }
+                    }
+                    if new_paid > vested {
+                        return Err(Error::InstallmentNotDue);
+                    }
+                }
+
+                p.amount_paid = new_paid;
+
+                // Record when the participant cleared their share
+                if p.has_fully_paid() && p.paid_at.is_none() {
+                    p.paid_at = Some(now);
+                }
             }
             updated_participants.push_back(p);
         }
 
         if !found {
-            panic!("Participant not found in split");
+            return Err(Error::ParticipantNotFound);
         }
 
+        let amount_collected = escrow
+            .amount_collected
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+
         // Transfer tokens from participant to escrow contract
         let token_address = storage::get_token(&env);
         let token_client = token::Client::new(&env, &token_address);
-        let contract_address = env.current_contract_address();
-        token_client.transfer(&participant, &contract_address, &amount);
+        token_client.transfer(&participant, &env.current_contract_address(), &amount);
+        Self::lock_funds(&env, &token_address, amount)?;
 
-        // Update split state
-        split.participants = updated_participants;
-        split.amount_collected += amount;
+        storage::add_participant_payment(&env, &split_id, &participant, amount)?;
+        storage::record_deposit(&env, &split_id, &participant, amount);
 
-        // Check if split is now fully funded
-        if split.status == SplitStatus::Pending {
-            split.status = SplitStatus::Active;
+        escrow.participants = updated_participants;
+        escrow.amount_collected = amount_collected;
+
+        let completed = escrow.is_fully_funded();
+        if completed {
+            escrow.status = EscrowStatus::Completed;
         }
 
-        // Save the updated split
-        storage::set_split(&env, split_id, &split);
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        let mut summary = storage::get_escrow_summary(&env);
+        summary.total_locked = summary
+            .total_locked
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        if completed {
+            summary.active -= 1;
+            summary.completed += 1;
+        }
+        storage::set_escrow_summary(&env, &summary);
+
+        events::emit_escrow_deposit(&env, &split_id, &participant, amount);
+
+        if completed {
+            events::emit_escrow_status_changed(
+                &env,
+                &split_id,
+                EscrowStatus::Active,
+                EscrowStatus::Completed,
+            );
+            Self::notify_completion_hook(&env, &split_id);
+        }
+
+        Ok(())
+    }
+
+    /// Allow or disallow participants to withdraw before completion
+    ///
+    /// I'm leaving this off by default so deposits are committed unless
+    /// the creator opts in.
+    pub fn set_allow_early_withdraw(env: Env, split_id: String, allow: bool) -> Result<(), Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        // Only the creator can change this
+        escrow.creator.require_auth();
+
+        storage::set_allow_early_withdraw(&env, &split_id, allow);
+
+        Ok(())
+    }
+
+    /// Withdraw a participant's deposit from an escrow that hasn't completed
+    ///
+    /// I'm refunding the full recorded payment and zeroing it, so the
+    /// participant can change their mind while the escrow is still `Active`.
+    pub fn withdraw_deposit(env: Env, split_id: String, participant: Address) -> Result<i128, Error> {
+        participant.require_auth();
+
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        if !storage::get_allow_early_withdraw(&env, &split_id) {
+            return Err(Error::WithdrawNotAllowed);
+        }
+
+        let paid = storage::get_participant_payment(&env, &split_id, &participant);
+        if paid <= 0 {
+            return Err(Error::NoFundsAvailable);
+        }
+
+        let mut updated_participants = Vec::new(&env);
+        for mut p in escrow.participants.iter() {
+            if p.address == participant {
+                p.amount_paid = 0;
+                p.paid_at = None;
+            }
+            updated_participants.push_back(p);
+        }
+
+        // Transfer the deposit back to the participant
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &participant, &paid);
+        Self::unlock_funds(&env, &token_address, paid);
+
+        storage::set_participant_payment(&env, &split_id, &participant, 0);
+
+        escrow.participants = updated_participants;
+        escrow.amount_collected -= paid;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        let mut summary = storage::get_escrow_summary(&env);
+        summary.total_locked -= paid;
+        storage::set_escrow_summary(&env, &summary);
+
+        events::emit_escrow_withdrawal(&env, &split_id, &participant, paid);
+
+        Ok(paid)
+    }
+
+    /// Pay a completed hashed-ID escrow out to its creator
+    ///
+    /// I'm mirroring `release_funds` for the string-keyed flow: everything
+    /// the escrow collected goes to the creator, and it moves to `Released`
+    /// so it can only pay out once. Returns the amount released.
+    pub fn release_escrow(env: Env, split_id: String) -> Result<i128, Error> {
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        match escrow.status {
+            EscrowStatus::Completed => {}
+            EscrowStatus::Released => return Err(Error::AlreadyReleased),
+            _ => return Err(Error::SplitNotFunded),
+        }
+
+        let amount = escrow.amount_collected;
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &escrow.creator, &amount);
+        Self::unlock_funds(&env, &token_address, amount);
+
+        escrow.status = EscrowStatus::Released;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        let mut summary = storage::get_escrow_summary(&env);
+        summary.total_locked -= amount;
+        summary.completed -= 1;
+        storage::set_escrow_summary(&env, &summary);
+
+        events::emit_escrow_released(&env, &split_id, &escrow.creator, amount);
+        events::emit_escrow_status_changed(
+            &env,
+            &split_id,
+            EscrowStatus::Completed,
+            EscrowStatus::Released,
+        );
+
+        Ok(amount)
+    }
+
+    /// Move an escrow whose deadline (plus grace) has passed to `Expired`
+    ///
+    /// Anyone can call this, so keepers can expire escrows on schedule.
+    /// I'm not transitioning from `deposit_escrow`: a rejected deposit rolls
+    /// back its writes and events, so the transition would never persist.
+    pub fn expire_escrow(env: Env, split_id: String) -> Result<(), Error> {
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if escrow.status != EscrowStatus::Active {
+            return Err(Error::EscrowNotActive);
+        }
+
+        if !escrow.is_expired(env.ledger().timestamp()) {
+            return Err(Error::NotExpired);
+        }
+
+        escrow.status = EscrowStatus::Expired;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        // Funds stay locked until refunded; only the active count changes
+        let mut summary = storage::get_escrow_summary(&env);
+        summary.active -= 1;
+        storage::set_escrow_summary(&env, &summary);
+
+        events::emit_expired(&env, &split_id, escrow.amount_collected);
+        events::emit_escrow_status_changed(
+            &env,
+            &split_id,
+            EscrowStatus::Active,
+            EscrowStatus::Expired,
+        );
+
+        Ok(())
+    }
+
+    /// Refund every participant of a cancelled or expired escrow in one call
+    ///
+    /// I'm pushing each recorded payment back and zeroing it, so the creator
+    /// can settle everyone at once instead of waiting on individual refunds.
+    /// Returns how many participants were refunded. Creator only.
+    pub fn refund_all(env: Env, split_id: String) -> Result<u32, Error> {
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        escrow.creator.require_auth();
+
+        if escrow.status != EscrowStatus::Cancelled && escrow.status != EscrowStatus::Expired {
+            return Err(Error::EscrowNotRefundable);
+        }
+
+        let refunded = Self::refund_payments(&env, &split_id, &mut escrow)?;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        Ok(refunded)
+    }
+
+    /// Refund every participant and cancel the escrow, whatever its status
+    ///
+    /// I'm keeping this as the admin's escape hatch for a critical bug: it
+    /// skips the usual status checks, returns every recorded payment, and
+    /// leaves the escrow `Cancelled`. Returns how many participants were
+    /// refunded. Admin only.
+    pub fn admin_emergency_refund(env: Env, split_id: String) -> Result<u32, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        // Released funds have left the contract; refunding would pay twice
+        if escrow.status == EscrowStatus::Released {
+            return Err(Error::AlreadyReleased);
+        }
+
+        let refunded = Self::refund_payments(&env, &split_id, &mut escrow)?;
+
+        let previous_status = escrow.status.clone();
+        escrow.status = EscrowStatus::Cancelled;
+        storage::set_escrow(&env, &split_id, &escrow);
+
+        let mut summary = storage::get_escrow_summary(&env);
+        match previous_status {
+            EscrowStatus::Active => summary.active -= 1,
+            EscrowStatus::Completed => summary.completed -= 1,
+            EscrowStatus::Cancelled | EscrowStatus::Expired | EscrowStatus::Released => {}
+        }
+        storage::set_escrow_summary(&env, &summary);
+
+        events::emit_emergency_refund(&env, &split_id, &admin, refunded);
+        if previous_status != EscrowStatus::Cancelled {
+            events::emit_escrow_status_changed(
+                &env,
+                &split_id,
+                previous_status,
+                EscrowStatus::Cancelled,
+            );
+        }
+
+        Ok(refunded)
+    }
+
+    /// Set how long before a deadline `emit_deadline_warning` may fire
+    ///
+    /// Admin only. Defaults to one day.
+    pub fn set_warning_window(env: Env, window: u64) {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        storage::set_warning_window(&env, window);
+    }
+
+    /// Emit a `deadline_approaching` event for an underfunded escrow
+    ///
+    /// Anyone can call this, so off-chain notifiers can poll it. I'm only
+    /// emitting when the deadline is within the warning window, the escrow is
+    /// still `Active` and short of its total, and no warning has gone out in
+    /// this window yet. Returns whether a warning was emitted.
+    pub fn emit_deadline_warning(env: Env, split_id: String) -> Result<bool, Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        let now = env.ledger().timestamp();
+        let window = storage::get_warning_window(&env, DEFAULT_WARNING_WINDOW);
+        let window_start = escrow.deadline.saturating_sub(window);
+
+        if escrow.status != EscrowStatus::Active
+            || escrow.is_fully_funded()
+            || now < window_start
+            || now > escrow.deadline
+        {
+            return Ok(false);
+        }
+
+        if let Some(last) = storage::get_last_deadline_warning(&env, &split_id) {
+            if last >= window_start {
+                return Ok(false);
+            }
+        }
+
+        storage::set_last_deadline_warning(&env, &split_id, now);
+        events::emit_deadline_approaching(
+            &env,
+            &split_id,
+            escrow.deadline,
+            escrow.total_amount - escrow.amount_collected,
+        );
+
+        Ok(true)
+    }
+
+    /// Attach an integrator reference (invoice number, order ID) to an escrow
+    ///
+    /// I'm keeping metadata out of `SplitEscrow` so integrations don't change
+    /// the core struct. Only the creator can set it; overwriting a key is
+    /// always allowed, but an escrow holds at most 16 distinct keys.
+    pub fn set_metadata(env: Env, split_id: String, key: Symbol, value: String) -> Result<(), Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+        escrow.creator.require_auth();
+
+        let mut keys = storage::get_metadata_keys(&env, &split_id);
+        if !keys.contains(&key) {
+            if keys.len() >= MAX_METADATA_ENTRIES {
+                return Err(Error::TooManyMetadataEntries);
+            }
+            keys.push_back(key.clone());
+            storage::set_metadata_keys(&env, &split_id, &keys);
+        }
+
+        storage::set_metadata(&env, &split_id, &key, &value);
+        Ok(())
+    }
+
+    /// Get an integrator reference attached to an escrow, if set
+    pub fn get_metadata(env: Env, split_id: String, key: Symbol) -> Option<String> {
+        storage::get_metadata(&env, &split_id, &key)
+    }
+
+    /// Sweep the rounding dust a split's payout left behind
+    ///
+    /// I'm tracking what the bonus payout couldn't divide evenly among the
+    /// eligible participants and holding it for the split. Only that residue
+    /// is swept; tokens held for other splits or sent to the contract
+    /// directly are never touched. The split must be `Released` or
+    /// `Cancelled`. Admin only.
+    pub fn sweep_dust(env: Env, split_id: u64, recipient: Address) -> Result<i128, Error> {
+        let admin = storage::get_admin(&env);
+        admin.require_auth();
+
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let status = storage::get_split(&env, split_id).status;
+        if status != SplitStatus::Released && status != SplitStatus::Cancelled {
+            return Err(Error::EscrowNotTerminal);
+        }
+
+        let dust = storage::get_dust(&env, split_id);
+        if dust <= 0 {
+            return Err(Error::NoFundsAvailable);
+        }
+
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+
+        storage::remove_dust(&env, split_id);
+        Self::unlock_funds(&env, &token_address, dust);
+        token_client.transfer(&env.current_contract_address(), &recipient, &dust);
+
+        Ok(dust)
+    }
+
+    /// Register a contract to be notified when an escrow completes
+    ///
+    /// I'm letting integrators hook into completion (e.g. to mint a receipt).
+    /// The hook is called with the split ID as its only argument.
+    pub fn register_completion_hook(
+        env: Env,
+        split_id: String,
+        hook_contract: Address,
+        fn_name: Symbol,
+    ) -> Result<(), Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        // Only the creator can register a hook
+        escrow.creator.require_auth();
+
+        storage::set_completion_hook(&env, &split_id, &hook_contract, &fn_name);
+
+        Ok(())
+    }
+
+    /// Set the time by which participants must fully pay to earn the bonus
+    ///
+    /// I'm leaving this to the creator; a deadline of 0 disables the bonus.
+    pub fn set_bonus_deadline(env: Env, split_id: u64, bonus_deadline: u64) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        split.creator.require_auth();
+
+        storage::set_bonus_deadline(&env, split_id, bonus_deadline);
+        Ok(())
+    }
+
+    /// Add to a split's early-payment bonus pool
+    ///
+    /// The pool is shared equally at release among participants who fully
+    /// paid by the bonus deadline, or returned to the sponsor if none did.
+    /// The creator must set the deadline first. One sponsor per split; they
+    /// may top the pool up.
+    pub fn fund_bonus(env: Env, split_id: u64, sponsor: Address, amount: i128) -> Result<(), Error> {
+        sponsor.require_auth();
+
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Without a deadline nobody can earn the pool
+        if storage::get_bonus_deadline(&env, split_id) == 0 {
+            return Err(Error::NoBonusDeadline);
+        }
+
+        // Bonuses only make sense while participants can still pay
+        match storage::get_split(&env, split_id).status {
+            SplitStatus::Pending | SplitStatus::Active => {}
+            SplitStatus::Completed => return Err(Error::SplitFullyFunded),
+            SplitStatus::Released => return Err(Error::SplitReleased),
+            SplitStatus::Cancelled => return Err(Error::SplitCancelled),
+        }
+
+        let pool = match storage::get_bonus_pool(&env, split_id) {
+            Some(pool) => {
+                if pool.sponsor != sponsor {
+                    return Err(Error::BonusSponsorMismatch);
+                }
+                BonusPool {
+                    sponsor,
+                    amount: pool.amount.checked_add(amount).ok_or(Error::Overflow)?,
+                }
+            }
+            None => BonusPool { sponsor, amount },
+        };
+
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&pool.sponsor, &env.current_contract_address(), &amount);
+        Self::lock_funds(&env, &token_address, amount)?;
+
+        storage::set_bonus_pool(&env, split_id, &pool);
+        Ok(())
+    }
+
+    /// Release funds from a completed split to the creator
+    ///
+    /// I'm restricting this to completed splits only for safety. A split
+    /// releases at most once; later calls return `AlreadyReleased`.
+    pub fn release_funds(env: Env, split_id: u64) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        if !Self::release_approved(&env, split_id) {
+            return Err(Error::InsufficientApprovals);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        Self::release_funds_internal(&env, split_id, split).map(|_| ())
+    }
+
+    /// Require sign-off from a set of approvers before a split can release
+    ///
+    /// I'm letting the creator pick who approves and how many approvals are
+    /// needed, e.g. 2 of 3 for high-value splits. The set is locked once
+    /// anyone has approved, so approvals can't be wiped or outvoted later.
+    pub fn set_release_approvers(
+        env: Env,
+        split_id: u64,
+        approvers: Vec<Address>,
+        required_approvals: u32,
+    ) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        split.creator.require_auth();
+
+        if required_approvals == 0 || required_approvals > approvers.len() {
+            return Err(Error::InvalidApprovers);
+        }
+
+        if let Some(existing) = storage::get_release_approvals(&env, split_id) {
+            if !existing.approvals.is_empty() {
+                return Err(Error::ApproversLocked);
+            }
+        }
+
+        storage::set_release_approvals(
+            &env,
+            split_id,
+            &ReleaseApprovals {
+                approvers,
+                required_approvals,
+                approvals: Vec::new(&env),
+            },
+        );
+        Ok(())
+    }
+
+    /// Record an approver's sign-off on releasing a split
+    pub fn approve_release(env: Env, split_id: u64, approver: Address) -> Result<(), Error> {
+        approver.require_auth();
+
+        let mut config =
+            storage::get_release_approvals(&env, split_id).ok_or(Error::NotApprover)?;
+
+        if !config.approvers.contains(&approver) {
+            return Err(Error::NotApprover);
+        }
+
+        if config.approvals.contains(&approver) {
+            return Err(Error::AlreadyApproved);
+        }
+
+        config.approvals.push_back(approver);
+        storage::set_release_approvals(&env, split_id, &config);
+        Ok(())
+    }
+
+    /// Release available funds to the creator for partial payments
+    pub fn release_partial(env: Env, split_id: u64) -> Result<i128, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let mut split = storage::get_split(&env, split_id);
+
+        if split.status == SplitStatus::Cancelled {
+            return Err(Error::SplitCancelled);
+        }
+
+        if split.status == SplitStatus::Released {
+            return Err(Error::SplitReleased);
+        }
+
+        if Self::is_fully_funded_internal(&split) {
+            return Err(Error::SplitFullyFunded);
+        }
+
+        let available = split.amount_collected - split.amount_released;
+        if available <= 0 {
+            return Err(Error::NoFundsAvailable);
+        }
+
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+        let contract_address = env.current_contract_address();
+        let recipient = Self::payout_address(&split);
+        token_client.transfer(&contract_address, &recipient, &available);
+        Self::unlock_funds(&env, &token_address, available);
+
+        split.amount_released += available;
+        Self::mark_participants_released(&env, &mut split);
+        storage::set_split(&env, split_id, &split);
+
+        events::emit_funds_released(
+            &env,
+            split_id,
+            &recipient,
+            available,
+            env.ledger().timestamp(),
+        );
+
+        Ok(available)
+    }
+
+    /// Get how much of a participant's payments has not been released yet
+    ///
+    /// I'm counting every release as covering everything collected so far,
+    /// which is how `release_partial` and `release_funds` pay out. Returns 0
+    /// for cancelled splits and when nothing is outstanding.
+    pub fn get_releasable(env: Env, split_id: u64, participant: Address) -> Result<i128, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        let p = split
+            .participants
+            .iter()
+            .find(|p| p.address == participant)
+            .ok_or(Error::ParticipantNotFound)?;
+
+        if split.status == SplitStatus::Cancelled {
+            return Ok(0);
+        }
+
+        Ok(p.amount_paid - p.amount_released)
+    }
+
+    /// Check if a split is fully funded
+    pub fn is_fully_funded(env: Env, split_id: u64) -> Result<bool, Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let split = storage::get_split(&env, split_id);
+        Ok(Self::is_fully_funded_internal(&split))
+    }
+
+    /// Change where a split's released funds are paid
+    ///
+    /// I'm letting the creator redirect payouts, e.g. before losing access
+    /// to their own address. `None` pays the creator again. Funds already
+    /// released stay where they went.
+    pub fn set_payout_recipient(
+        env: Env,
+        split_id: u64,
+        payout_recipient: Option<Address>,
+    ) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let mut split = storage::get_split(&env, split_id);
+        split.creator.require_auth();
+
+        if split.status == SplitStatus::Released {
+            return Err(Error::SplitReleased);
+        }
+
+        split.payout_recipient = payout_recipient;
+        storage::set_split(&env, split_id, &split);
+
+        Ok(())
+    }
+
+    /// Cancel a split and mark for refunds
+    ///
+    /// I'm allowing only the creator to cancel, and only while the split is
+    /// still `Pending` or `Active`. Once it has completed (or been released)
+    /// the deposits are spoken for and cancelling would strand them.
+    pub fn cancel_split(env: Env, split_id: u64, reason: String) -> Result<(), Error> {
+        if !storage::has_split(&env, split_id) {
+            return Err(Error::SplitNotFound);
+        }
+
+        let mut split = storage::get_split(&env, split_id);
+
+        // Only the creator can cancel
+        split.creator.require_auth();
+
+        if split.status != SplitStatus::Pending && split.status != SplitStatus::Active {
+            return Err(Error::CannotCancel);
+        }
+
+        // The reason is shown to participants, so bound it like a description
+        Self::validate_description(&reason)?;
+
+        // Mark as cancelled
+        let previous_status = split.status.clone();
+        split.status = SplitStatus::Cancelled;
+        split.cancel_reason = Some(reason.clone());
+        storage::set_split(&env, split_id, &split);
+
+        let token_address = storage::get_token(&env);
+        let token_client = token::Client::new(&env, &token_address);
+
+        // Nobody can earn the bonus now, so it goes back to the sponsor
+        if let Some(pool) = storage::get_bonus_pool(&env, split_id) {
+            storage::remove_bonus_pool(&env, split_id);
+            token_client.transfer(&env.current_contract_address(), &pool.sponsor, &pool.amount);
+            Self::unlock_funds(&env, &token_address, pool.amount);
+        }
+
+        // Unconfirmed deposits never counted toward the split, so return them
+        for p in split.participants.iter() {
+            if let Some(pending) = storage::get_pending_deposit(&env, split_id, &p.address) {
+                storage::remove_pending_deposit(&env, split_id, &p.address);
+                token_client.transfer(&env.current_contract_address(), &p.address, &pending.amount);
+                Self::unlock_funds(&env, &token_address, pending.amount);
+            }
+        }
+
+        // Emit cancellation event
+        events::emit_split_cancelled(&env, split_id, &reason);
+        events::emit_status_changed(&env, split_id, previous_status, SplitStatus::Cancelled);
 
-        // Emit deposit event
-        events::emit_deposit_received(&env, split_id, &participant, amount);
+        Ok(())
+    }
 
-        // Auto-release funds if fully funded
-        if Self::is_fully_funded_internal(&split) {
-            let _ = Self::release_funds_internal(&env, split_id, split);
-        }
+    /// Get split details by ID
+    pub fn get_split(env: Env, split_id: u64) -> Split {
+        storage::get_split(&env, split_id)
     }
 
-    /// Release funds from a completed split to the creator
+    /// Get the address that created a split
     ///
-    /// I'm restricting this to completed splits only for safety.
-    pub fn release_funds(env: Env, split_id: u64) -> Result<(), Error> {
+    /// I'm returning `SplitNotFound` for unknown IDs instead of panicking
+    /// like `get_split`.
+    pub fn get_split_creator(env: Env, split_id: u64) -> Result<Address, Error> {
         if !storage::has_split(&env, split_id) {
             return Err(Error::SplitNotFound);
         }
 
-        let split = storage::get_split(&env, split_id);
-        Self::release_funds_internal(&env, split_id, split).map(|_| ())
+        Ok(storage::get_split(&env, split_id).creator)
     }
 
-    /// Release available funds to the creator for partial payments
-    pub fn release_partial(env: Env, split_id: u64) -> Result<i128, Error> {
-        if !storage::has_split(&env, split_id) {
-            return Err(Error::SplitNotFound);
-        }
+    /// Check whether a split exists without loading it
+    ///
+    /// I'm exposing this so clients can probe an ID without catching the
+    /// panic `get_split` raises for unknown splits.
+    pub fn split_exists(env: Env, split_id: u64) -> bool {
+        storage::has_split(&env, split_id)
+    }
 
-        let mut split = storage::get_split(&env, split_id);
+    /// Check whether an escrow exists under a hashed ID
+    ///
+    /// I'm the hashed-ID counterpart of `split_exists`, so other contracts
+    /// can validate an escrow ID without handling `SplitNotFound`.
+    pub fn escrow_exists(env: Env, split_id: String) -> bool {
+        storage::has_escrow(&env, &split_id)
+    }
 
-        if split.status == SplitStatus::Cancelled {
-            return Err(Error::SplitCancelled);
+    /// Get escrow details by its hashed ID
+    ///
+    /// I'm reporting an active escrow past its deadline as `Expired` so
+    /// callers don't see a stale status. This is a view-only override:
+    /// nothing is written, and the stored status only changes through
+    /// `expire_escrow`, which still has to be called to update the summary.
+    pub fn get_escrow(env: Env, split_id: String) -> Result<SplitEscrow, Error> {
+        let mut escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        if escrow.is_expired(env.ledger().timestamp()) {
+            escrow.status = EscrowStatus::Expired;
         }
 
-        if split.status == SplitStatus::Released {
-            return Err(Error::SplitReleased);
-        }
+        Ok(escrow)
+    }
 
-        if Self::is_fully_funded_internal(&split) {
-            return Err(Error::SplitFullyFunded);
+    /// Check whether an escrow can still accept deposits
+    ///
+    /// I'm mirroring the gates `deposit_escrow` applies before it looks at
+    /// the participant, so clients can enable or disable their deposit UI
+    /// with one call. Unknown escrows, and every escrow while deposits are
+    /// paused, report `false`.
+    pub fn can_deposit(env: Env, split_id: String) -> bool {
+        if storage::get_paused(&env) {
+            return false;
         }
 
-        let available = split.amount_collected - split.amount_released;
-        if available <= 0 {
-            return Err(Error::NoFundsAvailable);
+        match storage::get_escrow(&env, &split_id) {
+            Some(escrow) => {
+                escrow.status == EscrowStatus::Active
+                    && !escrow.is_expired(env.ledger().timestamp())
+            }
+            None => false,
         }
+    }
 
-        let token_address = storage::get_token(&env);
-        let token_client = token::Client::new(&env, &token_address);
-        let contract_address = env.current_contract_address();
-        token_client.transfer(&contract_address, &split.creator, &available);
-
-        split.amount_released += available;
-        storage::set_split(&env, split_id, &split);
+    /// Get an escrow's timeline in one call
+    ///
+    /// I'm returning `(created_at, deadline, now)` so UIs can render a
+    /// timeline without a separate ledger query. `deadline` is the stored
+    /// deadline, without the grace period.
+    pub fn get_time_info(env: Env, split_id: String) -> Result<(u64, u64, u64), Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+        Ok((escrow.created_at, escrow.deadline, env.ledger().timestamp()))
+    }
 
-        events::emit_funds_released(
-            &env,
-            split_id,
-            &split.creator,
-            available,
-            env.ledger().timestamp(),
-        );
+    /// Get the escrows an address is a participant in, oldest first
+    ///
+    /// I'm keeping at most `MAX_PARTICIPANT_SPLITS` IDs per address, so a
+    /// long-lived address only sees its most recent escrows here.
+    pub fn get_splits_for_participant(env: Env, participant: Address) -> Vec<String> {
+        storage::get_participant_splits(&env, &participant)
+    }
 
-        Ok(available)
+    /// Get how many participants an escrow has
+    ///
+    /// I'm a narrow read for UIs that only show a headcount and don't need
+    /// the amounts `get_escrow` carries.
+    pub fn get_participant_count(env: Env, split_id: String) -> Result<u32, Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+        Ok(escrow.participants.len())
     }
 
-    /// Check if a split is fully funded
-    pub fn is_fully_funded(env: Env, split_id: u64) -> Result<bool, Error> {
-        if !storage::has_split(&env, split_id) {
-            return Err(Error::SplitNotFound);
+    /// Check whether an address is a participant of an escrow
+    ///
+    /// I'm exposing this for the dispute contract, which uses it to stop
+    /// outsiders raising disputes. Unknown escrows report `false`.
+    pub fn is_participant(env: Env, split_id: String, address: Address) -> bool {
+        match storage::get_escrow(&env, &split_id) {
+            Some(escrow) => escrow.participants.iter().any(|p| p.address == address),
+            None => false,
         }
-
-        let split = storage::get_split(&env, split_id);
-        Ok(Self::is_fully_funded_internal(&split))
     }
 
-    /// Cancel a split and mark for refunds
+    /// Get the participants of an escrow who still owe money
     ///
-    /// I'm allowing only the creator to cancel, and only if not fully completed.
-    pub fn cancel_split(env: Env, split_id: u64) {
-        let mut split = storage::get_split(&env, split_id);
+    /// I'm reading each participant's paid amount from the payment ledger
+    /// so the result matches `get_participant_payment`.
+    pub fn get_unpaid_participants(env: Env, split_id: String) -> Result<Vec<EscrowParticipant>, Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        let mut unpaid = Vec::new(&env);
+        for mut p in escrow.participants.iter() {
+            p.amount_paid = storage::get_participant_payment(&env, &split_id, &p.address);
+            if p.remaining_owed() > 0 {
+                unpaid.push_back(p);
+            }
+        }
 
-        // Only the creator can cancel
-        split.creator.require_auth();
+        Ok(unpaid)
+    }
 
-        // Can't cancel a completed split that's been released
-        if split.status == SplitStatus::Released {
-            panic!("Cannot cancel a released split");
+    /// Check that an escrow's collected total matches its payment ledger
+    ///
+    /// I'm summing each participant's recorded payment and comparing it to
+    /// `amount_collected`. This is an audit tool; a `false` means a bug has
+    /// mis-credited somewhere.
+    pub fn reconcile(env: Env, split_id: String) -> Result<bool, Error> {
+        let escrow = storage::get_escrow(&env, &split_id).ok_or(Error::SplitNotFound)?;
+
+        let mut recorded: i128 = 0;
+        for p in escrow.participants.iter() {
+            recorded = recorded
+                .checked_add(storage::get_participant_payment(&env, &split_id, &p.address))
+                .ok_or(Error::Overflow)?;
         }
 
-        // Mark as cancelled
-        split.status = SplitStatus::Cancelled;
-        storage::set_split(&env, split_id, &split);
+        Ok(recorded == escrow.amount_collected)
+    }
 
-        // Emit cancellation event
-        events::emit_split_cancelled(&env, split_id);
+    /// Get a participant's recent deposits into an escrow
+    ///
+    /// Returns up to the last 50 deposits as `(timestamp, amount)`, oldest first.
+    pub fn get_deposit_history(env: Env, split_id: String, participant: Address) -> Vec<(u64, i128)> {
+        storage::get_deposit_history(&env, &split_id, &participant)
     }
 
-    /// Get split details by ID
-    pub fn get_split(env: Env, split_id: u64) -> Split {
-        storage::get_split(&env, split_id)
+    /// Get aggregate totals across all hashed-ID escrows
+    ///
+    /// Returns `(count, total_locked, active, completed)`. I'm reading
+    /// running aggregates rather than scanning, so this is O(1).
+    pub fn get_escrow_summary(env: Env) -> (u64, i128, u32, u32) {
+        let summary = storage::get_escrow_summary(&env);
+        (
+            storage::get_escrow_count(&env),
+            summary.total_locked,
+            summary.active,
+            summary.completed,
+        )
     }
 
     /// Get the contract admin
@@ -326,8 +1557,8 @@ impl SplitEscrowContract {
     }
 
     /// Get insurance ID for a split
-    pub fn get_split_insurance(env: Env, split_id: u64) -> Option<String> {
-        storage::get_split_to_insurance(&env, &split_id.to_string())
+    pub fn get_split_insurance(env: Env, split_id: String) -> Option<String> {
+        storage::get_split_to_insurance(&env, &split_id)
     }
 
     /// Track user split usage for rewards calculation
@@ -417,7 +1648,7 @@ impl SplitEscrowContract {
         let total_rewards = creation_rewards + participation_rewards + volume_rewards;
         
         // Update rewards earned
-        let mut updated_rewards = rewards;
+        let mut updated_rewards = rewards.clone();
         updated_rewards.rewards_earned = total_rewards;
         storage::set_user_rewards(&env, &user, &updated_rewards);
 
@@ -437,14 +1668,8 @@ impl SplitEscrowContract {
         env: Env,
         user: Address,
     ) -> Result<i128, Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
-        caller.require_auth();
-
-        // Ensure caller is claiming their own rewards
-        if caller != user {
-            return Err(Error::UserNotFound);
-        }
+        // Only the user can claim their own rewards
+        user.require_auth();
 
         // Get user rewards data
         let mut rewards = storage::get_user_rewards(&env, &user)
@@ -483,43 +1708,25 @@ impl SplitEscrowContract {
     /// This function allows users to submit verification requests with evidence.
     pub fn submit_verification(
         env: Env,
+        caller: Address,
         split_id: String,
         receipt_hash: String,
     ) -> Result<String, Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
         caller.require_auth();
 
         // Check if split exists
-        let split_id_num = {
-            let mut result = 0u64;
-            let chars = split_id.clone();
-            for i in 0..chars.len() {
-                let char_val = chars.get(i).unwrap();
-                match char_val {
-                    '0' => result = result * 10 + 0,
-                    '1' => result = result * 10 + 1,
-                    '2' => result = result * 10 + 2,
-                    '3' => result = result * 10 + 3,
-                    '4' => result = result * 10 + 4,
-                    '5' => result = result * 10 + 5,
-                    '6' => result = result * 10 + 6,
-                    '7' => result = result * 10 + 7,
-                    '8' => result = result * 10 + 8,
-                    '9' => result = result * 10 + 9,
-                    _ => {} // Skip non-digit characters
-                }
-            }
-            result
-        };
-
+        let split_id_num = Self::parse_split_id(&split_id)?;
         if !storage::has_split(&env, split_id_num) {
             return Err(Error::SplitNotFound);
         }
 
-        // Check if verification already exists
-        if storage::has_verification_request(&env, &split_id) {
-            return Err(Error::VerificationAlreadyExists);
+        // Check if a verification is already pending for this split
+        for id in storage::get_split_verifications(&env, &split_id).iter() {
+            if let Some(existing) = storage::get_verification_request(&env, &id) {
+                if existing.status == types::VerificationStatus::Pending {
+                    return Err(Error::VerificationAlreadyExists);
+                }
+            }
         }
 
         // Generate verification ID
@@ -529,7 +1736,7 @@ impl SplitEscrowContract {
         let request = types::VerificationRequest {
             verification_id: verification_id.clone(),
             split_id: split_id.clone(),
-            requester: caller,
+            requester: caller.clone(),
             receipt_hash: receipt_hash.clone(),
             evidence_url: None,
             submitted_at: env.ledger().timestamp(),
@@ -553,11 +1760,10 @@ impl SplitEscrowContract {
     /// This function allows authorized oracles to verify split legitimacy.
     pub fn verify_split(
         env: Env,
+        caller: Address,
         verification_id: String,
         verified: bool,
     ) -> Result<(), Error> {
-        // Get caller's address (require_auth for the caller)
-        let caller = env.current_contract_address();
         caller.require_auth();
 
         // Get verification request
@@ -583,7 +1789,7 @@ impl SplitEscrowContract {
         } else {
             types::VerificationStatus::Rejected
         };
-        request.verified_by = Some(caller);
+        request.verified_by = Some(caller.clone());
         request.verified_at = Some(env.ledger().timestamp());
 
         if !verified {
@@ -614,19 +1820,16 @@ impl SplitEscrowContract {
         let mut latest_timestamp = 0u64;
 
         for verification_id in verification_ids.iter() {
-            if let Some(request) = storage::get_verification_request(&env, verification_id) {
+            if let Some(request) = storage::get_verification_request(&env, &verification_id) {
+                let verified_at = request.verified_at.unwrap_or(0);
                 match request.status {
-                    types::VerificationStatus::Verified => {
-                        if request.verified_at.unwrap_or(0) > latest_timestamp {
-                            latest_timestamp = request.verified_at.unwrap();
-                            latest_status = types::VerificationStatus::Verified;
-                        }
+                    types::VerificationStatus::Verified if verified_at >= latest_timestamp => {
+                        latest_timestamp = verified_at;
+                        latest_status = types::VerificationStatus::Verified;
                     },
-                    types::VerificationStatus::Rejected => {
-                        if request.verified_at.unwrap_or(0) > latest_timestamp {
-                            latest_timestamp = request.verified_at.unwrap();
-                            latest_status = types::VerificationStatus::Rejected;
-                        }
+                    types::VerificationStatus::Rejected if verified_at >= latest_timestamp => {
+                        latest_timestamp = verified_at;
+                        latest_status = types::VerificationStatus::Rejected;
                     },
                     _ => {}
                 }
@@ -635,4 +1838,387 @@ impl SplitEscrowContract {
 
         latest_status
     }
+
+    // ============================================
+    // Private Helper Functions
+    // ============================================
+
+    /// Parse a decimal split ID string into its numeric form
+    fn parse_split_id(split_id: &String) -> Result<u64, Error> {
+        let mut digits = [0u8; 20];
+        let len = split_id.len() as usize;
+        if len == 0 || len > digits.len() {
+            return Err(Error::SplitNotFound);
+        }
+        split_id.copy_into_slice(&mut digits[..len]);
+
+        let mut result = 0u64;
+        for d in digits[..len].iter() {
+            if !d.is_ascii_digit() {
+                return Err(Error::SplitNotFound);
+            }
+            result = result
+                .checked_mul(10)
+                .and_then(|r| r.checked_add((d - b'0') as u64))
+                .ok_or(Error::SplitNotFound)?;
+        }
+        Ok(result)
+    }
+
+    /// Add an escrow to each of its participants' indexes
+    ///
+    /// I'm dropping the oldest entry once an index is full rather than
+    /// failing, since anyone can name an address as a participant and a
+    /// full index mustn't block new escrows.
+    fn index_participants(env: &Env, escrow: &SplitEscrow) {
+        for p in escrow.participants.iter() {
+            let mut split_ids = storage::get_participant_splits(env, &p.address);
+            if split_ids.contains(&escrow.split_id) {
+                continue;
+            }
+            if split_ids.len() >= MAX_PARTICIPANT_SPLITS {
+                split_ids.pop_front();
+            }
+            split_ids.push_back(escrow.split_id.clone());
+            storage::set_participant_splits(env, &p.address, &split_ids);
+        }
+    }
+
+    /// Whether a split has collected its full total, less its tolerance
+    fn is_fully_funded_internal(split: &Split) -> bool {
+        split.amount_collected >= split.total_amount - split.completion_tolerance
+    }
+
+    /// Count a deposit the contract already holds toward a split
+    ///
+    /// I'm sharing this between direct deposits and confirmed pending ones
+    /// so both activate, complete and, when opted in, auto-release the split
+    /// the same way.
+    fn apply_deposit(
+        env: &Env,
+        split_id: u64,
+        mut split: Split,
+        participant: &Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        let mut remaining_owed: i128 = 0;
+        let mut newly_paid = false;
+        let mut updated_participants = Vec::new(env);
+
+        for i in 0..split.participants.len() {
+            let mut p = split.participants.get(i).unwrap();
+            if &p.address == participant {
+                p.amount_paid = p.amount_paid.checked_add(amount).ok_or(Error::Overflow)?;
+                newly_paid = !p.has_paid && p.amount_paid >= p.share_amount;
+                p.has_paid = p.amount_paid >= p.share_amount;
+                remaining_owed = p.share_amount - p.amount_paid;
+            }
+            updated_participants.push_back(p);
+        }
+
+        // Update split state
+        split.participants = updated_participants;
+        split.amount_collected = split
+            .amount_collected
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+
+        // The first deposit activates the split; deposits are never zero here
+        let was_pending = split.status == SplitStatus::Pending;
+        if was_pending {
+            split.status = SplitStatus::Active;
+        }
+
+        // Check if split is now fully funded
+        let fully_funded = Self::is_fully_funded_internal(&split);
+        if fully_funded {
+            split.status = SplitStatus::Completed;
+        }
+
+        // Save the updated split
+        storage::set_split(env, split_id, &split);
+
+        // Paying in full by the bonus deadline earns a share of the bonus;
+        // a deadline of 0 means no bonus, not "anyone paying at time 0"
+        let bonus_deadline = storage::get_bonus_deadline(env, split_id);
+        if newly_paid && bonus_deadline > 0 && env.ledger().timestamp() <= bonus_deadline {
+            storage::add_bonus_eligible(env, split_id, participant);
+        }
+
+        // Emit deposit event
+        events::emit_deposit_received(env, split_id, participant, amount);
+
+        if was_pending {
+            events::emit_status_changed(env, split_id, SplitStatus::Pending, SplitStatus::Active);
+        }
+
+        if fully_funded {
+            events::emit_status_changed(env, split_id, SplitStatus::Active, SplitStatus::Completed);
+
+            // Release now if the creator opted in and no sign-off is pending
+            if storage::get_auto_release(env, split_id) && Self::release_approved(env, split_id) {
+                Self::release_funds_internal(env, split_id, split)?;
+            }
+        }
+
+        Ok(remaining_owed)
+    }
+
+    /// Where a split's released funds go: the payout recipient, else the creator
+    fn payout_address(split: &Split) -> Address {
+        split.payout_recipient.clone().unwrap_or(split.creator.clone())
+    }
+
+    /// Record that each participant's payments so far have been released
+    fn mark_participants_released(env: &Env, split: &mut Split) {
+        let mut updated = Vec::new(env);
+        for mut p in split.participants.iter() {
+            p.amount_released = p.amount_paid;
+            updated.push_back(p);
+        }
+        split.participants = updated;
+    }
+
+    /// Pay everything collected but not yet released out to the creator
+    ///
+    /// I'm checking the `released` flag before anything else so a second
+    /// call can never double-pay, and setting it before the transfer.
+    fn release_funds_internal(env: &Env, split_id: u64, mut split: Split) -> Result<i128, Error> {
+        if split.released {
+            return Err(Error::AlreadyReleased);
+        }
+
+        if split.status != SplitStatus::Completed {
+            return Err(Error::SplitNotFunded);
+        }
+
+        let amount = split.amount_collected - split.amount_released;
+
+        split.amount_released = split.amount_collected;
+        Self::mark_participants_released(env, &mut split);
+        split.released = true;
+        split.status = SplitStatus::Released;
+        storage::set_split(env, split_id, &split);
+
+        let token_address = storage::get_token(env);
+        let token_client = token::Client::new(env, &token_address);
+        let recipient = Self::payout_address(&split);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+        Self::unlock_funds(env, &token_address, amount);
+
+        events::emit_escrow_completed(env, split_id, split.total_amount);
+        events::emit_funds_released(
+            env,
+            split_id,
+            &recipient,
+            amount,
+            env.ledger().timestamp(),
+        );
+        events::emit_status_changed(env, split_id, SplitStatus::Completed, SplitStatus::Released);
+
+        Self::settle_bonus(env, split_id);
+
+        Ok(amount)
+    }
+
+    /// Pay out a split's bonus pool, if any
+    ///
+    /// I'm sharing it equally among eligible participants. The stroops that
+    /// don't divide evenly stay locked as the split's dust for `sweep_dust`.
+    /// With nobody eligible the sponsor gets the pool back.
+    fn settle_bonus(env: &Env, split_id: u64) {
+        let pool = match storage::get_bonus_pool(env, split_id) {
+            Some(pool) => pool,
+            None => return,
+        };
+        storage::remove_bonus_pool(env, split_id);
+
+        let token_address = storage::get_token(env);
+        let token_client = token::Client::new(env, &token_address);
+        let contract_address = env.current_contract_address();
+
+        let eligible = storage::get_bonus_eligible(env, split_id);
+        if eligible.is_empty() {
+            Self::unlock_funds(env, &token_address, pool.amount);
+            token_client.transfer(&contract_address, &pool.sponsor, &pool.amount);
+            return;
+        }
+
+        let count = eligible.len() as i128;
+        let share = pool.amount / count;
+        let remainder = pool.amount - share * count;
+        Self::unlock_funds(env, &token_address, pool.amount - remainder);
+        for participant in eligible.iter() {
+            token_client.transfer(&contract_address, &participant, &share);
+            events::emit_bonus_paid(env, split_id, &participant, share);
+        }
+        if remainder > 0 {
+            storage::set_dust(env, split_id, remainder);
+        }
+    }
+
+    /// Return every recorded payment on an escrow and zero it
+    ///
+    /// I'm updating the participants, `amount_collected`, and the locked
+    /// total here; the caller persists the escrow. Returns how many
+    /// participants were refunded.
+    fn refund_payments(env: &Env, split_id: &String, escrow: &mut SplitEscrow) -> Result<u32, Error> {
+        let token_address = storage::get_token(env);
+        let token_client = token::Client::new(env, &token_address);
+        let contract_address = env.current_contract_address();
+
+        let mut refunded: u32 = 0;
+        let mut total_refunded: i128 = 0;
+        let mut updated_participants = Vec::new(env);
+
+        for mut p in escrow.participants.iter() {
+            let paid = storage::get_participant_payment(env, split_id, &p.address);
+            if paid > 0 {
+                token_client.transfer(&contract_address, &p.address, &paid);
+                storage::set_participant_payment(env, split_id, &p.address, 0);
+                events::emit_escrow_refund(env, split_id, &p.address, paid);
+
+                total_refunded = total_refunded.checked_add(paid).ok_or(Error::Overflow)?;
+                refunded += 1;
+            }
+            p.amount_paid = 0;
+            p.paid_at = None;
+            updated_participants.push_back(p);
+        }
+
+        escrow.participants = updated_participants;
+        escrow.amount_collected -= total_refunded;
+        Self::unlock_funds(env, &token_address, total_refunded);
+
+        let mut summary = storage::get_escrow_summary(env);
+        summary.total_locked -= total_refunded;
+        storage::set_escrow_summary(env, &summary);
+
+        Ok(refunded)
+    }
+
+    /// Validate and store a new hashed-ID escrow
+    ///
+    /// Callers are responsible for requiring the creator's authorization.
+    #[allow(clippy::too_many_arguments)]
+    fn store_hashed_escrow(
+        env: &Env,
+        creator: Address,
+        description: String,
+        total_amount: i128,
+        participant_addresses: Vec<Address>,
+        participant_shares: Vec<i128>,
+        deadline: u64,
+        grace_period_secs: u64,
+        rounding_beneficiary: Option<Address>,
+    ) -> Result<String, Error> {
+        Self::validate_description(&description)?;
+
+        // Validate inputs
+        if participant_addresses.len() != participant_shares.len() {
+            panic!("Participant addresses and shares must have the same length");
+        }
+
+        if participant_addresses.is_empty() {
+            panic!("At least one participant is required");
+        }
+
+        if participant_addresses.len() > MAX_ESCROW_PARTICIPANTS {
+            return Err(Error::TooManyParticipants);
+        }
+
+        let mut shares_sum: i128 = 0;
+        for i in 0..participant_shares.len() {
+            shares_sum += participant_shares.get(i).unwrap();
+        }
+        if shares_sum != total_amount {
+            panic!("Participant shares must sum to total amount");
+        }
+
+        let split_id = storage::generate_hashed_escrow_id(env, &creator, &description);
+        if storage::has_escrow(env, &split_id) {
+            return Err(Error::EscrowAlreadyExists);
+        }
+
+        let mut participants = Vec::new(env);
+        for i in 0..participant_addresses.len() {
+            participants.push_back(EscrowParticipant::new(
+                participant_addresses.get(i).unwrap(),
+                participant_shares.get(i).unwrap(),
+            ));
+        }
+
+        let mut escrow = create_escrow(
+            env,
+            split_id.clone(),
+            creator.clone(),
+            description,
+            total_amount,
+            participants,
+            deadline,
+        );
+        escrow.grace_period_secs = grace_period_secs;
+        escrow.rounding_beneficiary = rounding_beneficiary;
+
+        storage::set_escrow(env, &split_id, &escrow);
+        storage::increment_escrow_count(env);
+        Self::index_participants(env, &escrow);
+
+        let mut summary = storage::get_escrow_summary(env);
+        summary.active += 1;
+        storage::set_escrow_summary(env, &summary);
+
+        events::emit_escrow_created(env, &split_id, &creator, total_amount);
+
+        Ok(split_id)
+    }
+
+    /// Whether a split has the sign-off it needs to release
+    ///
+    /// Splits without approvers configured are always approved.
+    fn release_approved(env: &Env, split_id: u64) -> bool {
+        match storage::get_release_approvals(env, split_id) {
+            Some(config) => config.approvals.len() >= config.required_approvals,
+            None => true,
+        }
+    }
+
+    /// Add to the amount of a token held for splits and escrows
+    fn lock_funds(env: &Env, token: &Address, amount: i128) -> Result<(), Error> {
+        let locked = storage::get_locked_total(env, token)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        storage::set_locked_total(env, token, locked);
+        Ok(())
+    }
+
+    /// Subtract from the amount of a token held for splits and escrows
+    fn unlock_funds(env: &Env, token: &Address, amount: i128) {
+        let locked = storage::get_locked_total(env, token) - amount;
+        storage::set_locked_total(env, token, locked);
+    }
+
+    /// Reject empty descriptions and ones over `MAX_DESCRIPTION_LEN` bytes
+    ///
+    /// I'm bounding this so a caller can't bloat the stored split entry.
+    fn validate_description(description: &String) -> Result<(), Error> {
+        if description.is_empty() {
+            return Err(Error::EmptyDescription);
+        }
+        if description.len() > MAX_DESCRIPTION_LEN {
+            return Err(Error::DescriptionTooLong);
+        }
+        Ok(())
+    }
+
+    /// Invoke the registered completion hook, if any
+    ///
+    /// I'm using `try_invoke_contract` so a failing hook can't roll back
+    /// the completion that triggered it. This is strictly best-effort.
+    fn notify_completion_hook(env: &Env, split_id: &String) {
+        if let Some((hook_contract, fn_name)) = storage::get_completion_hook(env, split_id) {
+            let args: Vec<Val> = Vec::from_array(env, [split_id.into_val(env)]);
+            let _ = env.try_invoke_contract::<Val, InvokeError>(&hook_contract, &fn_name, args);
+        }
+    }
 }
@@ -6,39 +6,13 @@
 //! This module includes both original storage patterns and the enhanced
 //! escrow storage keys as specified in issue #59.
 
-use soroban_sdk::{contracttype, Address, Env, String, symbol_short, Vec, Symbol};
-use crate::types::{Split, SplitEscrow};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec, Symbol};
+use crate::types::{
+    BonusPool, Error, EscrowSummary, OracleConfig, PendingDeposit, ReleaseApprovals, Split,
+    SplitEscrow, UserActivity, UserRewards, VerificationRequest,
+};
 
 
-const ADMIN: Symbol = symbol_short!("ADMIN");
-const INIT: Symbol = symbol_short!("INIT");
-
-pub fn is_initialized(env: &Env) -> bool {
-    env.storage().instance().has(&INIT)
-}
-
-pub fn set_initialized(env: &Env) {
-    env.storage().instance().set(&INIT, &true);
-}
-
-pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&ADMIN, admin);
-}
-
-pub fn get_admin(env: &Env) -> Address {
-    env.storage().instance().get(&ADMIN).unwrap()
-}
-
-pub fn save_escrow(env: &Env, escrow: &SplitEscrow) {
-    env.storage()
-        .persistent()
-        .set(&escrow.split_id, escrow);
-}
-
-pub fn get_escrow(env: &Env, split_id: &String) -> Option<SplitEscrow> {
-    env.storage().persistent().get(split_id)
-}
-
 // ============================================
 // Original Storage Keys
 // ============================================
@@ -61,6 +35,48 @@ pub enum DataKey {
 
     /// Whether the contract is initialized
     Initialized,
+
+    /// Admin-configured cap on a split's total amount (0 = no cap)
+    MaxTotalAmount,
+
+    /// Optional release sign-off for a split
+    ReleaseApprovals(u64),
+
+    /// How long before an escrow deadline a warning may be emitted
+    WarningWindow,
+
+    /// Sponsor-funded early-payment bonus for a split
+    BonusPool(u64),
+
+    /// Participants who must fully pay by this time to earn the bonus
+    BonusDeadline(u64),
+
+    /// Participants who fully paid before the bonus deadline
+    BonusEligible(u64),
+
+    /// Ledgers a deposit waits before it can be confirmed (0 = immediate)
+    DepositConfirmations,
+
+    /// A participant's deposit awaiting confirmation
+    PendingDeposit(u64, Address),
+
+    /// Whether participants must accept a split before depositing
+    RequireAcceptance,
+
+    /// Participants who have accepted a split
+    Acceptances(u64),
+
+    /// Split created for a creator's idempotency key
+    IdempotencyKey(Address, BytesN<32>),
+
+    /// Whether the completing deposit releases a split (absent = manual)
+    AutoRelease(u64),
+
+    /// Rounding residue a split's bonus payout couldn't divide evenly
+    Dust(u64),
+
+    /// Whether the admin has paused deposits
+    Paused,
 }
 
 // ============================================
@@ -82,9 +98,48 @@ pub enum StorageKey {
     /// Maps: (split_id, participant_address) -> i128 amount
     ParticipantPayment(String, Address),
 
+    /// Optional installment schedule for a participant
+    /// Maps: (split_id, participant_address) -> Vec<(due_timestamp, amount)>
+    Installments(String, Address),
+
+    /// External contract to notify when an escrow completes
+    /// Maps: split_id -> (hook_contract, fn_name)
+    CompletionHook(String),
+
+    /// Whether participants may withdraw deposits before completion
+    /// Maps: split_id -> bool
+    EarlyWithdraw(String),
+
+    /// Recent deposits by a participant, oldest first
+    /// Maps: (split_id, participant_address) -> Vec<(timestamp, amount)>
+    DepositHistory(String, Address),
+
+    /// When a deadline warning was last emitted for an escrow
+    /// Maps: split_id -> u64 timestamp
+    DeadlineWarning(String),
+
+    /// Integrator metadata attached to an escrow
+    /// Maps: (split_id, key) -> String
+    Metadata(String, Symbol),
+
+    /// Metadata keys set on an escrow, to bound how many there are
+    /// Maps: split_id -> Vec<Symbol>
+    MetadataKeys(String),
+
+    /// Escrows an address participates in, oldest first
+    /// Maps: participant_address -> Vec<split_id>
+    ParticipantSplits(Address),
+
     /// Total number of escrows created
     EscrowCount,
 
+    /// Running aggregates across all escrows
+    EscrowSummary,
+
+    /// Tokens held on behalf of splits and escrows
+    /// Maps: token_address -> i128 amount
+    LockedTotal(Address),
+
     /// Admin address (shared with original)
     Admin,
 
@@ -169,6 +224,44 @@ pub fn set_token(env: &Env, token: &Address) {
     );
 }
 
+/// Get the cap on a split's total amount, 0 when uncapped
+pub fn get_max_total_amount(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxTotalAmount)
+        .unwrap_or(0)
+}
+
+/// Set the cap on a split's total amount
+pub fn set_max_total_amount(env: &Env, max_total_amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaxTotalAmount, &max_total_amount);
+    env.storage().persistent().extend_ttl(
+        &DataKey::MaxTotalAmount,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Get the deadline warning window in seconds
+pub fn get_warning_window(env: &Env, default: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WarningWindow)
+        .unwrap_or(default)
+}
+
+/// Set the deadline warning window in seconds
+pub fn set_warning_window(env: &Env, window: u64) {
+    env.storage().persistent().set(&DataKey::WarningWindow, &window);
+    env.storage().persistent().extend_ttl(
+        &DataKey::WarningWindow,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
 // ============================================
 // Original Split Counter Functions
 // ============================================
@@ -188,6 +281,14 @@ pub fn get_next_split_id(env: &Env) -> u64 {
     next
 }
 
+/// Get the highest split ID issued so far (0 if none)
+pub fn get_split_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SplitCounter)
+        .unwrap_or(0)
+}
+
 // ============================================
 // Original Split Storage Functions
 // ============================================
@@ -216,6 +317,230 @@ pub fn set_split(env: &Env, split_id: u64, split: &Split) {
         .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
 }
 
+/// Get the release sign-off configured for a split, if any
+pub fn get_release_approvals(env: &Env, split_id: u64) -> Option<ReleaseApprovals> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReleaseApprovals(split_id))
+}
+
+/// Store the release sign-off for a split
+pub fn set_release_approvals(env: &Env, split_id: u64, approvals: &ReleaseApprovals) {
+    let key = DataKey::ReleaseApprovals(split_id);
+    env.storage().persistent().set(&key, approvals);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the bonus pool funded for a split, if any
+pub fn get_bonus_pool(env: &Env, split_id: u64) -> Option<BonusPool> {
+    env.storage().persistent().get(&DataKey::BonusPool(split_id))
+}
+
+/// Store the bonus pool for a split
+pub fn set_bonus_pool(env: &Env, split_id: u64, pool: &BonusPool) {
+    let key = DataKey::BonusPool(split_id);
+    env.storage().persistent().set(&key, pool);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Remove a split's bonus pool once it has been paid out
+pub fn remove_bonus_pool(env: &Env, split_id: u64) {
+    env.storage().persistent().remove(&DataKey::BonusPool(split_id));
+}
+
+/// Get the rounding dust held for a split (0 = none)
+pub fn get_dust(env: &Env, split_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Dust(split_id))
+        .unwrap_or(0)
+}
+
+/// Hold a split's rounding dust until it is swept
+pub fn set_dust(env: &Env, split_id: u64, amount: i128) {
+    let key = DataKey::Dust(split_id);
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Clear a split's rounding dust once swept
+pub fn remove_dust(env: &Env, split_id: u64) {
+    env.storage().persistent().remove(&DataKey::Dust(split_id));
+}
+
+/// Get how many ledgers a deposit waits before confirmation
+pub fn get_deposit_confirmations(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DepositConfirmations)
+        .unwrap_or(0)
+}
+
+/// Set how many ledgers a deposit waits before confirmation
+pub fn set_deposit_confirmations(env: &Env, confirmations: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DepositConfirmations, &confirmations);
+    env.storage().persistent().extend_ttl(
+        &DataKey::DepositConfirmations,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Check whether participants must accept a split before depositing
+pub fn get_require_acceptance(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RequireAcceptance)
+        .unwrap_or(false)
+}
+
+/// Whether the admin has paused deposits
+pub fn get_paused(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+/// Pause or resume deposits
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().persistent().set(&DataKey::Paused, &paused);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Paused,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Set whether participants must accept a split before depositing
+pub fn set_require_acceptance(env: &Env, required: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RequireAcceptance, &required);
+    env.storage().persistent().extend_ttl(
+        &DataKey::RequireAcceptance,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Get the participants who have accepted a split
+pub fn get_acceptances(env: &Env, split_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Acceptances(split_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Store the participants who have accepted a split
+pub fn set_acceptances(env: &Env, split_id: u64, acceptances: &Vec<Address>) {
+    let key = DataKey::Acceptances(split_id);
+    env.storage().persistent().set(&key, acceptances);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get a participant's deposit awaiting confirmation, if any
+pub fn get_pending_deposit(env: &Env, split_id: u64, participant: &Address) -> Option<PendingDeposit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingDeposit(split_id, participant.clone()))
+}
+
+/// Store a participant's deposit awaiting confirmation
+pub fn set_pending_deposit(env: &Env, split_id: u64, participant: &Address, pending: &PendingDeposit) {
+    let key = DataKey::PendingDeposit(split_id, participant.clone());
+    env.storage().persistent().set(&key, pending);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Remove a participant's pending deposit once settled or refunded
+pub fn remove_pending_deposit(env: &Env, split_id: u64, participant: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingDeposit(split_id, participant.clone()));
+}
+
+/// Get the split a creator already created under an idempotency key, if any
+pub fn get_idempotent_split(env: &Env, creator: &Address, key: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IdempotencyKey(creator.clone(), key.clone()))
+}
+
+/// Record the split created under a creator's idempotency key
+pub fn set_idempotent_split(env: &Env, creator: &Address, key: &BytesN<32>, split_id: u64) {
+    let key = DataKey::IdempotencyKey(creator.clone(), key.clone());
+    env.storage().persistent().set(&key, &split_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Whether the deposit that completes a split also releases it
+pub fn get_auto_release(env: &Env, split_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AutoRelease(split_id))
+        .unwrap_or(false)
+}
+
+/// Opt a split into releasing on its completing deposit
+pub fn set_auto_release(env: &Env, split_id: u64) {
+    let key = DataKey::AutoRelease(split_id);
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the bonus deadline for a split (0 = no bonus)
+pub fn get_bonus_deadline(env: &Env, split_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BonusDeadline(split_id))
+        .unwrap_or(0)
+}
+
+/// Set the bonus deadline for a split
+pub fn set_bonus_deadline(env: &Env, split_id: u64, deadline: u64) {
+    let key = DataKey::BonusDeadline(split_id);
+    env.storage().persistent().set(&key, &deadline);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the participants who earned a split's bonus, in the order they qualified
+pub fn get_bonus_eligible(env: &Env, split_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BonusEligible(split_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Mark a participant as having earned a split's bonus
+pub fn add_bonus_eligible(env: &Env, split_id: u64, participant: &Address) {
+    let key = DataKey::BonusEligible(split_id);
+    let mut eligible = get_bonus_eligible(env, split_id);
+    eligible.push_back(participant.clone());
+    env.storage().persistent().set(&key, &eligible);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
 /// Remove a split (for cleanup if needed)
 #[allow(dead_code)]
 pub fn remove_split(env: &Env, split_id: u64) {
@@ -253,13 +578,46 @@ pub fn increment_escrow_count(env: &Env) -> u64 {
     next
 }
 
+/// Get the running escrow aggregates
+pub fn get_escrow_summary(env: &Env) -> EscrowSummary {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::EscrowSummary)
+        .unwrap_or_default()
+}
+
+/// Store the running escrow aggregates
+pub fn set_escrow_summary(env: &Env, summary: &EscrowSummary) {
+    env.storage()
+        .persistent()
+        .set(&StorageKey::EscrowSummary, summary);
+    env.storage().persistent().extend_ttl(
+        &StorageKey::EscrowSummary,
+        LEDGER_TTL_THRESHOLD,
+        LEDGER_TTL_PERSISTENT,
+    );
+}
+
+/// Get how much of a token the contract holds for splits and escrows
+pub fn get_locked_total(env: &Env, token: &Address) -> i128 {
+    let key = StorageKey::LockedTotal(token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Store how much of a token the contract holds for splits and escrows
+pub fn set_locked_total(env: &Env, token: &Address, amount: i128) {
+    let key = StorageKey::LockedTotal(token.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
 /// Get an escrow by split_id
-pub fn get_escrow(env: &Env, split_id: &String) -> SplitEscrow {
-    let key = StorageKey::Escrow(split_id.clone());
+pub fn get_escrow(env: &Env, split_id: &String) -> Option<SplitEscrow> {
     env.storage()
         .persistent()
-        .get(&key)
-        .expect("Escrow not found")
+        .get(&StorageKey::Escrow(split_id.clone()))
 }
 
 /// Check if an escrow exists
@@ -309,17 +667,18 @@ pub fn set_participant_payment(env: &Env, split_id: &String, participant: &Addre
 /// Add to the payment amount for a participant
 ///
 /// I'm providing this helper to simplify the common case of
-/// adding a new payment to an existing balance.
+/// adding a new payment to an existing balance. Returns
+/// `Error::Overflow` instead of wrapping on adversarial amounts.
 pub fn add_participant_payment(
     env: &Env,
     split_id: &String,
     participant: &Address,
     amount: i128,
-) -> i128 {
+) -> Result<i128, Error> {
     let current = get_participant_payment(env, split_id, participant);
-    let new_total = current + amount;
+    let new_total = current.checked_add(amount).ok_or(Error::Overflow)?;
     set_participant_payment(env, split_id, participant, new_total);
-    new_total
+    Ok(new_total)
 }
 
 /// Check if a participant has any recorded payment
@@ -335,10 +694,169 @@ pub fn remove_participant_payment(env: &Env, split_id: &String, participant: &Ad
     env.storage().persistent().remove(&key);
 }
 
+// ============================================
+// Completion Hook Storage
+// ============================================
+
+/// Get the completion hook registered for an escrow, if any
+pub fn get_completion_hook(env: &Env, split_id: &String) -> Option<(Address, Symbol)> {
+    let key = StorageKey::CompletionHook(split_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Register the completion hook for an escrow
+pub fn set_completion_hook(env: &Env, split_id: &String, hook_contract: &Address, fn_name: &Symbol) {
+    let key = StorageKey::CompletionHook(split_id.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(hook_contract.clone(), fn_name.clone()));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+// ============================================
+// Early Withdrawal Storage
+// ============================================
+
+/// Check whether early withdrawal is enabled for an escrow
+pub fn get_allow_early_withdraw(env: &Env, split_id: &String) -> bool {
+    let key = StorageKey::EarlyWithdraw(split_id.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Enable or disable early withdrawal for an escrow
+pub fn set_allow_early_withdraw(env: &Env, split_id: &String, allow: bool) {
+    let key = StorageKey::EarlyWithdraw(split_id.clone());
+    env.storage().persistent().set(&key, &allow);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+// ============================================
+// Installment Schedule Storage
+// ============================================
+
+/// Get the installment schedule for a participant (empty if none)
+pub fn get_installments(env: &Env, split_id: &String, participant: &Address) -> Vec<(u64, i128)> {
+    let key = StorageKey::Installments(split_id.clone(), participant.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Set the installment schedule for a participant
+pub fn set_installments(
+    env: &Env,
+    split_id: &String,
+    participant: &Address,
+    schedule: &Vec<(u64, i128)>,
+) {
+    let key = StorageKey::Installments(split_id.clone(), participant.clone());
+    env.storage().persistent().set(&key, schedule);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Most deposit history entries kept per participant
+const MAX_DEPOSIT_HISTORY: u32 = 50;
+
+/// Get a participant's recent deposits as `(timestamp, amount)` pairs
+pub fn get_deposit_history(env: &Env, split_id: &String, participant: &Address) -> Vec<(u64, i128)> {
+    let key = StorageKey::DepositHistory(split_id.clone(), participant.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append a deposit to a participant's history, dropping the oldest past the cap
+pub fn record_deposit(env: &Env, split_id: &String, participant: &Address, amount: i128) {
+    let key = StorageKey::DepositHistory(split_id.clone(), participant.clone());
+    let mut history = get_deposit_history(env, split_id, participant);
+    if history.len() >= MAX_DEPOSIT_HISTORY {
+        history.pop_front();
+    }
+    history.push_back((env.ledger().timestamp(), amount));
+    env.storage().persistent().set(&key, &history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
 // ============================================
 // Utility Functions
 // ============================================
 
+/// Get when a deadline warning was last emitted for an escrow
+pub fn get_last_deadline_warning(env: &Env, split_id: &String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::DeadlineWarning(split_id.clone()))
+}
+
+/// Record that a deadline warning was emitted for an escrow
+pub fn set_last_deadline_warning(env: &Env, split_id: &String, timestamp: u64) {
+    let key = StorageKey::DeadlineWarning(split_id.clone());
+    env.storage().persistent().set(&key, &timestamp);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get a metadata value attached to an escrow
+pub fn get_metadata(env: &Env, split_id: &String, key: &Symbol) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Metadata(split_id.clone(), key.clone()))
+}
+
+/// Attach a metadata value to an escrow
+pub fn set_metadata(env: &Env, split_id: &String, key: &Symbol, value: &String) {
+    let storage_key = StorageKey::Metadata(split_id.clone(), key.clone());
+    env.storage().persistent().set(&storage_key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(&storage_key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the metadata keys set on an escrow
+pub fn get_metadata_keys(env: &Env, split_id: &String) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::MetadataKeys(split_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the metadata keys set on an escrow
+pub fn set_metadata_keys(env: &Env, split_id: &String, keys: &Vec<Symbol>) {
+    let key = StorageKey::MetadataKeys(split_id.clone());
+    env.storage().persistent().set(&key, keys);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get the escrows an address participates in
+pub fn get_participant_splits(env: &Env, participant: &Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::ParticipantSplits(participant.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the escrows an address participates in
+pub fn set_participant_splits(env: &Env, participant: &Address, split_ids: &Vec<String>) {
+    let key = StorageKey::ParticipantSplits(participant.clone());
+    env.storage().persistent().set(&key, split_ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
+}
+
 /// Generate a unique escrow ID string
 ///
 /// I'm combining a counter with a prefix for readable IDs.
@@ -350,6 +868,32 @@ pub fn generate_escrow_id(env: &Env) -> String {
     String::from_str(env, "escrow")
 }
 
+/// Generate a hashed escrow ID string
+///
+/// I'm hashing the creator, description, and ledger sequence so IDs are
+/// neither sequential nor guessable, plus the escrow count as a nonce so
+/// repeats in one ledger (e.g. cloning twice) still get distinct IDs. The
+/// result looks like `esc_` followed by 16 hex characters (the first 8
+/// bytes of the SHA-256 digest).
+pub fn generate_hashed_escrow_id(env: &Env, creator: &Address, description: &String) -> String {
+    let mut input = Bytes::new(env);
+    input.append(&creator.clone().to_xdr(env));
+    input.append(&description.clone().to_xdr(env));
+    input.append(&Bytes::from_slice(env, &env.ledger().sequence().to_be_bytes()));
+    input.append(&Bytes::from_slice(env, &get_escrow_count(env).to_be_bytes()));
+    let hash = env.crypto().sha256(&input).to_array();
+
+    const HEX_CHARS: &[u8] = b"0123456789abcdef";
+    let mut id = [0u8; 20];
+    id[..4].copy_from_slice(b"esc_");
+    for (i, byte) in hash[..8].iter().enumerate() {
+        id[4 + i * 2] = HEX_CHARS[(byte >> 4) as usize];
+        id[5 + i * 2] = HEX_CHARS[(byte & 0x0F) as usize];
+    }
+
+    String::from_str(env, core::str::from_utf8(&id).unwrap_or("esc_"))
+}
+
 // ============================================
 // Insurance Storage Functions
 // ============================================
@@ -476,6 +1020,14 @@ pub fn add_insurance_claim(env: &Env, insurance_id: &String, claim_id: &String)
         .extend_ttl(&key, LEDGER_TTL_THRESHOLD, LEDGER_TTL_PERSISTENT);
 }
 
+/// Get the claim IDs filed against an insurance policy
+pub fn get_insurance_claims(env: &Env, insurance_id: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::InsuranceClaims(insurance_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
 /// Storage keys for rewards system
 #[derive(Clone)]
 #[contracttype]
@@ -569,31 +1121,37 @@ pub fn get_next_verification_id(env: &Env) -> String {
     env.storage().persistent().set(&key, &(counter + 1));
     
     // Convert counter to string
-    format_number_as_string(&env, counter)
-}
-
-/// Helper to format number as string (reused from rewards)
-fn format_number_as_string(env: &Env, num: u64) -> String {
-    match num {
-        0 => String::from_str(env, "0"),
-        1 => String::from_str(env, "1"),
-        2 => String::from_str(env, "2"),
-        3 => String::from_str(env, "3"),
-        4 => String::from_str(env, "4"),
-        5 => String::from_str(env, "5"),
-        6 => String::from_str(env, "6"),
-        7 => String::from_str(env, "7"),
-        8 => String::from_str(env, "8"),
-        9 => String::from_str(env, "9"),
-        10 => String::from_str(env, "10"),
-        _ => String::from_str(env, "999"),
+    format_number_as_string(env, counter)
+}
+
+/// Get how many verification IDs have been issued
+pub fn get_verification_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&OracleStorageKey::VerificationCounter)
+        .unwrap_or(0)
+}
+
+/// Format a number as a decimal string
+pub fn format_number_as_string(env: &Env, num: u64) -> String {
+    let mut digits = [0u8; 20];
+    let mut start = digits.len();
+    let mut n = num;
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
     }
+    String::from_bytes(env, &digits[start..])
 }
 
 /// Get all verification requests for a split
 pub fn get_split_verifications(env: &Env, split_id: &String) -> Vec<String> {
     let mut verification_ids = Vec::new(env);
-    let counter = get_next_verification_id(env);
+    let counter = get_verification_count(env);
     
     // Search through all verification IDs
     for i in 0..counter {
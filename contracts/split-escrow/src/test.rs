@@ -6,14 +6,13 @@
 #![cfg(test)]
 
 extern crate std;
-use std::string::ToString;
 
 use super::*;
 use soroban_sdk::{
-    symbol_short, testutils::Address as _, testutils::Events as _, testutils::Ledger as _, token, Address, Env, String,
-    Symbol, TryIntoVal, Vec,
+    contract, contractimpl, symbol_short, testutils::Address as _, testutils::Events as _, testutils::Ledger as _,
+    testutils::{MockAuth, MockAuthInvoke}, token, Address, Env, IntoVal, String, Symbol,
+    TryIntoVal, Vec,
 };
-use soroban_sdk::token::StellarAssetClient;
 use std::panic::{catch_unwind, AssertUnwindSafe};
 
 /// Helper to create a test environment and contract client
@@ -29,7 +28,9 @@ fn setup_test() -> (
     env.mock_all_auths();
 
     let token_admin = Address::generate(&env);
-    let token_id = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
     let token_client = token::Client::new(&env, &token_id);
     let token_admin_client = token::StellarAssetClient::new(&env, &token_id);
 
@@ -53,6 +54,34 @@ fn initialize_contract(client: &SplitEscrowContractClient, admin: &Address, toke
     client.initialize(admin, token);
 }
 
+/// Helper to create a hashed-ID escrow with the given participants and shares
+fn create_test_escrow(
+    env: &Env,
+    client: &SplitEscrowContractClient,
+    creator: &Address,
+    participants: &[(Address, i128)],
+    deadline: u64,
+) -> String {
+    let mut addresses = Vec::new(env);
+    let mut shares = Vec::new(env);
+    let mut total: i128 = 0;
+    for (address, share) in participants.iter() {
+        addresses.push_back(address.clone());
+        shares.push_back(*share);
+        total += share;
+    }
+
+    client.create_split_hashed(
+        creator,
+        &String::from_str(env, "Test escrow"),
+        &total,
+        &addresses,
+        &shares,
+        &deadline,
+        &0,
+    )
+}
+
 /// Helper to convert u64 to String in no_std environment
 fn u64_to_string(env: &Env, num: u64) -> String {
     // For simplicity in tests, we'll use basic pattern matching
@@ -120,7 +149,7 @@ fn test_create_split() {
     shares.push_back(50_0000000i128);
     shares.push_back(50_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &total_amount, &addresses, &shares);
+    let split_id = client.create_split(&creator, &description, &total_amount, &addresses, &shares, &0, &None, &None, &true);
 
     assert_eq!(split_id, 1);
 
@@ -151,7 +180,7 @@ fn test_create_split_invalid_shares() {
     let mut shares = Vec::new(&env);
     shares.push_back(50_0000000i128);
 
-    client.create_split(&creator, &description, &total_amount, &addresses, &shares);
+    client.create_split(&creator, &description, &total_amount, &addresses, &shares, &0, &None, &None, &true);
 }
 
 #[test]
@@ -166,114 +195,244 @@ fn test_create_split_no_participants() {
     let addresses: Vec<Address> = Vec::new(&env);
     let shares: Vec<i128> = Vec::new(&env);
 
-    client.create_split(&creator, &description, &0, &addresses, &shares);
+    client.create_split(&creator, &description, &0, &addresses, &shares, &0, &None, &None, &true);
 }
 
-// ============================================
-// Deposit Tests
-// ============================================
-
 #[test]
-fn test_deposit() {
-    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
+#[should_panic(expected = "Participant addresses and shares must have the same length")]
+fn test_create_split_mismatched_lengths() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
 
     let creator = Address::generate(&env);
-    let participant = Address::generate(&env);
-
-    let description = String::from_str(&env, "Test split");
-    let total_amount: i128 = 100_0000000;
 
     let mut addresses = Vec::new(&env);
-    addresses.push_back(participant.clone());
+    addresses.push_back(Address::generate(&env));
+    addresses.push_back(Address::generate(&env));
 
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &total_amount, &addresses, &shares);
+    client.create_split(
+        &creator,
+        &String::from_str(&env, "Mismatched"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+}
 
-    token_admin_client.mint(&participant, &100_0000000i128);
+#[test]
+fn test_create_split_too_many_participants() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-    // Make a deposit
-    client.deposit(&split_id, &participant, &50_0000000);
+    let creator = Address::generate(&env);
 
-    let split = client.get_split(&split_id);
-    assert_eq!(split.status, SplitStatus::Active);
-    assert_eq!(split.amount_collected, 50_0000000);
+    let mut addresses = Vec::new(&env);
+    let mut shares = Vec::new(&env);
+    for _ in 0..101 {
+        addresses.push_back(Address::generate(&env));
+        shares.push_back(1_0000000i128);
+    }
 
-    // Complete the deposit
-    client.deposit(&split_id, &participant, &50_0000000);
+    let result = client.try_create_split(
+        &creator,
+        &String::from_str(&env, "Too many"),
+        &101_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(Error::TooManyParticipants)));
+}
 
-    let split = client.get_split(&split_id);
-    assert_eq!(split.status, SplitStatus::Released);
-    assert_eq!(split.amount_collected, 100_0000000);
-    assert_eq!(split.amount_released, 100_0000000);
+#[test]
+fn test_create_split_over_max_total_rejected() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-    let creator_balance = token_client.balance(&creator);
-    assert_eq!(creator_balance, 100_0000000);
+    client.set_max_total_amount(&10_000_000_000);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let total_amount: i128 = 100_000_000_000; // an extra zero
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant);
+    let mut shares = Vec::new(&env);
+    shares.push_back(total_amount);
+
+    let result = client.try_create_split(
+        &creator,
+        &String::from_str(&env, "Typo"),
+        &total_amount,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(Error::AmountTooLarge)));
 }
 
 #[test]
-fn test_deposit_exceeds_share() {
-    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+fn test_create_split_zero_max_total_uncapped() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
 
+    client.set_max_total_amount(&0);
+
     let creator = Address::generate(&env);
     let participant = Address::generate(&env);
-
-    let description = String::from_str(&env, "Test split");
+    let total_amount: i128 = i128::MAX / 2;
 
     let mut addresses = Vec::new(&env);
-    addresses.push_back(participant.clone());
+    addresses.push_back(participant);
+    let mut shares = Vec::new(&env);
+    shares.push_back(total_amount);
+
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Big but intended"),
+        &total_amount,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(client.get_split(&split_id).total_amount, total_amount);
+}
 
+#[test]
+fn test_create_split_description_too_long() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant);
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
+    let long = "x".repeat(281);
+    let result = client.try_create_split(
+        &creator,
+        &String::from_str(&env, &long),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(Error::DescriptionTooLong)));
 
-    token_admin_client.mint(&participant, &200_0000000i128);
+    let result = client.try_create_split(
+        &creator,
+        &String::from_str(&env, ""),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(Error::EmptyDescription)));
 
-    // Try to overpay
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        client.deposit(&split_id, &participant, &150_0000000)
-    }));
-    assert!(result.is_err());
+    // Exactly at the limit is fine
+    let at_limit = "x".repeat(280);
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, &at_limit),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(client.get_split(&split_id).description.len(), 280);
 }
 
-// ============================================
-// Cancel Tests
-// ============================================
-
 #[test]
-fn test_cancel_split() {
+fn test_split_exists() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
 
     let creator = Address::generate(&env);
-    let participant = Address::generate(&env);
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(Address::generate(&env));
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
 
-    let description = String::from_str(&env, "Test split");
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
 
-    let mut addresses = Vec::new(&env);
-    addresses.push_back(participant);
+    assert!(client.split_exists(&split_id));
+    assert!(!client.split_exists(&424_242));
+}
+
+#[test]
+fn test_get_split_creator() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
+    let creator = Address::generate(&env);
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(Address::generate(&env));
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
-
-    client.cancel_split(&split_id);
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
 
-    let split = client.get_split(&split_id);
-    assert_eq!(split.status, SplitStatus::Cancelled);
+    assert_eq!(client.get_split_creator(&split_id), creator);
+    assert_eq!(
+        client.try_get_split_creator(&424_242),
+        Err(Ok(Error::SplitNotFound))
+    );
 }
 
 // ============================================
-// Release Tests
+// Deposit Tests
 // ============================================
 
 #[test]
-fn test_release_funds() {
+fn test_deposit() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
 
@@ -281,6 +440,7 @@ fn test_release_funds() {
     let participant = Address::generate(&env);
 
     let description = String::from_str(&env, "Test split");
+    let total_amount: i128 = 100_0000000;
 
     let mut addresses = Vec::new(&env);
     addresses.push_back(participant.clone());
@@ -288,1379 +448,3244 @@ fn test_release_funds() {
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
+    let split_id = client.create_split(&creator, &description, &total_amount, &addresses, &shares, &0, &None, &None, &true);
 
-    // Complete the split (auto-release should occur)
     token_admin_client.mint(&participant, &100_0000000i128);
-    client.deposit(&split_id, &participant, &100_0000000);
+
+    // Make a deposit
+    let remaining = client.deposit(&split_id, &participant, &50_0000000);
+    assert_eq!(remaining, 50_0000000);
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Active);
+    assert_eq!(split.amount_collected, 50_0000000);
+
+    // Complete the deposit
+    let remaining = client.deposit(&split_id, &participant, &50_0000000);
+    assert_eq!(remaining, 0);
 
     let split = client.get_split(&split_id);
     assert_eq!(split.status, SplitStatus::Released);
+    assert_eq!(split.amount_collected, 100_0000000);
+    assert_eq!(split.amount_released, 100_0000000);
 
     let creator_balance = token_client.balance(&creator);
     assert_eq!(creator_balance, 100_0000000);
-
-    // Manual release should be blocked after auto-release
-    let result = catch_unwind(AssertUnwindSafe(|| client.release_funds(&split_id)));
-    assert!(result.is_err());
 }
 
 #[test]
-fn test_release_incomplete_split() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+fn test_deposit_without_auto_release_waits_for_release() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
 
     let creator = Address::generate(&env);
     let participant = Address::generate(&env);
 
-    let description = String::from_str(&env, "Test split");
-
     let mut addresses = Vec::new(&env);
-    addresses.push_back(participant);
+    addresses.push_back(participant.clone());
 
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Manual release"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &false,
+    );
 
-    // Try to release without completing deposits
-    let result = catch_unwind(AssertUnwindSafe(|| client.release_funds(&split_id)));
-    assert!(result.is_err());
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+
+    // Completed, but nothing moves until the creator releases
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Completed);
+    assert_eq!(split.amount_released, 0);
+    assert_eq!(token_client.balance(&creator), 0);
+
+    client.release_funds(&split_id);
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Released);
+    assert_eq!(token_client.balance(&creator), 100_0000000);
 }
 
-// ============================================
-// Partial Release and Funding Checks
-// ============================================
+#[test]
+fn test_deposit_completes_within_tolerance() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant1.clone());
+    addresses.push_back(participant2.clone());
+    let mut shares = Vec::new(&env);
+    shares.push_back(50_0000000i128);
+    shares.push_back(50_0000000i128);
+
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Rounded split"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &2,
+        &None,
+        &None,
+        &true,
+    );
+
+    token_admin_client.mint(&participant1, &50_0000000i128);
+    token_admin_client.mint(&participant2, &50_0000000i128);
+    client.deposit(&split_id, &participant1, &50_0000000);
+
+    // One stroop short is within the tolerance of 2
+    let remaining = client.deposit(&split_id, &participant2, &49_9999999);
+    assert_eq!(remaining, 1);
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Released);
+    assert_eq!(split.amount_collected, 99_9999999);
+    assert_eq!(token_client.balance(&creator), 99_9999999);
+
+    // A tolerance can't cover the whole total
+    let mut small_shares = Vec::new(&env);
+    small_shares.push_back(50i128);
+    small_shares.push_back(50i128);
+    let result = client.try_create_split(
+        &creator,
+        &String::from_str(&env, "All tolerance"),
+        &100,
+        &addresses,
+        &small_shares,
+        &100,
+        &None,
+        &None,
+        &true,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
 
 #[test]
-fn test_is_fully_funded() {
+fn test_deposit_requires_acceptance() {
     let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
+    client.set_require_acceptance(&true);
 
     let creator = Address::generate(&env);
     let participant = Address::generate(&env);
 
-    let description = String::from_str(&env, "Funding check");
-
     let mut addresses = Vec::new(&env);
     addresses.push_back(participant.clone());
-
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Consent first"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
 
-    token_admin_client.mint(&participant, &50_0000000i128);
-    client.deposit(&split_id, &participant, &50_0000000);
+    token_admin_client.mint(&participant, &100_0000000i128);
+    let result = client.try_deposit(&split_id, &participant, &50_0000000);
+    assert_eq!(result, Err(Ok(Error::NotAccepted)));
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Pending);
 
-    let funded = client.is_fully_funded(&split_id);
-    assert!(!funded);
+    client.accept_split(&split_id, &participant);
+    assert_eq!(client.get_acceptances(&split_id).len(), 1);
 
-    token_admin_client.mint(&participant, &50_0000000i128);
     client.deposit(&split_id, &participant, &50_0000000);
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Active);
 
-    let funded = client.is_fully_funded(&split_id);
-    assert!(funded);
+    let result = client.try_accept_split(&split_id, &creator);
+    assert_eq!(result, Err(Ok(Error::ParticipantNotFound)));
 }
 
 #[test]
-fn test_release_partial() {
+fn test_deposit_waits_for_confirmation() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
+    client.set_deposit_confirmations(&2);
 
     let creator = Address::generate(&env);
     let participant = Address::generate(&env);
 
-    let description = String::from_str(&env, "Partial release");
-
     let mut addresses = Vec::new(&env);
     addresses.push_back(participant.clone());
-
     let mut shares = Vec::new(&env);
     shares.push_back(100_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
-
-    token_admin_client.mint(&participant, &60_0000000i128);
-    client.deposit(&split_id, &participant, &60_0000000);
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Settled later"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
 
-    let released = client.release_partial(&split_id);
-    assert_eq!(released, 60_0000000);
+    token_admin_client.mint(&participant, &100_0000000i128);
+    let remaining = client.deposit(&split_id, &participant, &60_0000000);
+    assert_eq!(remaining, 40_0000000);
 
+    // The tokens are held, but not counted toward the split yet
+    assert_eq!(token_client.balance(&participant), 40_0000000);
     let split = client.get_split(&split_id);
-    assert_eq!(split.status, SplitStatus::Active);
-    assert_eq!(split.amount_released, 60_0000000);
+    assert_eq!(split.amount_collected, 0);
+    assert_eq!(split.status, SplitStatus::Pending);
 
-    let creator_balance = token_client.balance(&creator);
-    assert_eq!(creator_balance, 60_0000000);
+    // Pending deposits still count against what the participant owes
+    let result = client.try_deposit(&split_id, &participant, &50_0000000);
+    assert!(result.is_err());
 
-    token_admin_client.mint(&participant, &40_0000000i128);
-    client.deposit(&split_id, &participant, &40_0000000);
+    let result = client.try_confirm_deposit(&split_id, &participant);
+    assert_eq!(result, Err(Ok(Error::DepositNotConfirmed)));
+
+    env.ledger().with_mut(|l| l.sequence_number += 2);
+    assert_eq!(client.confirm_deposit(&split_id, &participant), 40_0000000);
 
     let split = client.get_split(&split_id);
-    assert_eq!(split.status, SplitStatus::Released);
-    assert_eq!(split.amount_released, 100_0000000);
-}
+    assert_eq!(split.amount_collected, 60_0000000);
+    assert_eq!(split.status, SplitStatus::Active);
 
-// ============================================
-// Event Emission Tests
-// ============================================
+    let result = client.try_confirm_deposit(&split_id, &participant);
+    assert_eq!(result, Err(Ok(Error::NoPendingDeposit)));
+}
 
 #[test]
-fn test_events_emitted_on_auto_release() {
-    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+fn test_pending_deposit_refunded_after_completion() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
+    client.set_deposit_confirmations(&1);
 
     let creator = Address::generate(&env);
-    let participant = Address::generate(&env);
-
-    let description = String::from_str(&env, "Event check");
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let late = Address::generate(&env);
 
     let mut addresses = Vec::new(&env);
-    addresses.push_back(participant.clone());
-
+    addresses.push_back(first.clone());
+    addresses.push_back(second.clone());
+    addresses.push_back(late.clone());
     let mut shares = Vec::new(&env);
-    shares.push_back(100_0000000i128);
+    shares.push_back(50_0000000i128);
+    shares.push_back(40_0000000i128);
+    shares.push_back(10_0000000i128);
 
-    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares);
+    // Completes once it is at most 10 XLM short
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Close enough"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &10_0000000,
+        &None,
+        &None,
+        &false,
+    );
 
-    token_admin_client.mint(&participant, &100_0000000i128);
-    client.deposit(&split_id, &participant, &100_0000000);
+    for (participant, amount) in [(&first, 50_0000000i128), (&second, 40_0000000), (&late, 10_0000000)] {
+        token_admin_client.mint(participant, &amount);
+        client.deposit(&split_id, participant, &amount);
+    }
 
-    let events = env.events().all();
-    let mut has_completed = false;
-    let mut has_released = false;
-
-    for i in 0..events.len() {
-        let event = events.get(i).unwrap();
-        let topics = &event.1;
-        let data = &event.2;
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    client.confirm_deposit(&split_id, &first);
+    client.confirm_deposit(&split_id, &second);
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Completed);
 
-        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
-        if topic == symbol_short!("completed") {
-            let payload: (u64, i128) = data.try_into_val(&env).unwrap();
-            assert_eq!(payload.0, split_id);
-            assert_eq!(payload.1, 100_0000000);
-            has_completed = true;
-        }
-        if topic == symbol_short!("released") {
-            let payload: (u64, Address, i128, u64) = data.try_into_val(&env).unwrap();
-            assert_eq!(payload.0, split_id);
-            assert_eq!(payload.1, creator);
-            assert_eq!(payload.2, 100_0000000);
-            has_released = true;
-        }
-    }
+    // The split no longer needs the last deposit, so it goes back
+    assert_eq!(client.confirm_deposit(&split_id, &late), 0);
+    assert_eq!(token_client.balance(&late), 10_0000000);
+    assert_eq!(client.get_split(&split_id).amount_collected, 90_0000000);
 
-    assert!(has_completed);
-    assert!(has_released);
+    client.release_funds(&split_id);
+    assert_eq!(token_client.balance(&creator), 90_0000000);
+    assert_eq!(token_client.balance(&client.address), 0);
 }
 
-// ============================================
-// Enhanced Escrow Data Structure Tests (Issue #59)
-// ============================================
-
 #[test]
-fn test_escrow_status_values() {
-    // I'm verifying that all EscrowStatus variants are distinct and usable
-    let active = EscrowStatus::Active;
-    let completed = EscrowStatus::Completed;
-    let cancelled = EscrowStatus::Cancelled;
-    let expired = EscrowStatus::Expired;
-
-    assert_eq!(active, EscrowStatus::Active);
-    assert_ne!(active, completed);
-    assert_ne!(completed, cancelled);
-    assert_ne!(cancelled, expired);
-}
+fn test_deposit_requires_participant_auth() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-#[test]
-fn test_escrow_participant_creation() {
-    let env = Env::default();
-    let address = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let payer = Address::generate(&env);
 
-    let participant = EscrowParticipant::new(address.clone(), 100_0000000);
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
 
-    assert_eq!(participant.address, address);
-    assert_eq!(participant.amount_owed, 100_0000000);
-    assert_eq!(participant.amount_paid, 0);
-    assert!(participant.paid_at.is_none());
-}
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
 
-#[test]
-fn test_escrow_participant_validation() {
-    let env = Env::default();
-    let address = Address::generate(&env);
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Own share only"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
 
-    // Valid participant
-    let valid = EscrowParticipant {
-        address: address.clone(),
-        amount_owed: 100,
-        amount_paid: 50,
-        paid_at: None,
-    };
-    assert!(valid.validate().is_ok());
+    token_admin_client.mint(&participant, &100_0000000i128);
 
-    // Overpaid participant (invalid)
-    let overpaid = EscrowParticipant {
-        address: address.clone(),
-        amount_owed: 100,
-        amount_paid: 150,
-        paid_at: None,
-    };
-    assert!(overpaid.validate().is_err());
+    // Someone else signs, trying to credit the participant's share
+    env.mock_auths(&[MockAuth {
+        address: &payer,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "deposit",
+            args: (split_id, participant.clone(), 50_0000000i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    let result = client.try_deposit(&split_id, &participant, &50_0000000);
+    assert!(result.is_err());
 
-    // Negative amount (invalid)
-    let negative = EscrowParticipant {
-        address: address.clone(),
-        amount_owed: -100,
-        amount_paid: 0,
-        paid_at: None,
-    };
-    assert!(negative.validate().is_err());
+    let split = client.get_split(&split_id);
+    assert_eq!(split.amount_collected, 0);
 }
 
 #[test]
-fn test_escrow_participant_helpers() {
-    let env = Env::default();
-    let address = Address::generate(&env);
+fn test_deposit_exceeds_share() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-    let participant = EscrowParticipant {
-        address: address.clone(),
-        amount_owed: 100,
-        amount_paid: 60,
-        paid_at: None,
-    };
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
 
-    assert!(!participant.has_fully_paid());
-    assert_eq!(participant.remaining_owed(), 40);
+    let description = String::from_str(&env, "Test split");
 
-    let fully_paid = EscrowParticipant {
-        address: address.clone(),
-        amount_owed: 100,
-        amount_paid: 100,
-        paid_at: Some(12345),
-    };
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
 
-    assert!(fully_paid.has_fully_paid());
-    assert_eq!(fully_paid.remaining_owed(), 0);
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+
+    token_admin_client.mint(&participant, &200_0000000i128);
+
+    // Try to overpay
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        client.deposit(&split_id, &participant, &150_0000000)
+    }));
+    assert!(result.is_err());
 }
 
 #[test]
-fn test_split_escrow_creation() {
-    let env = Env::default();
+fn test_deposit_overflow_returns_error() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
+    let participant = Address::generate(&env);
 
-    let mut participants = Vec::new(&env);
-    participants.push_back(EscrowParticipant::new(participant1, 50_0000000));
-    participants.push_back(EscrowParticipant::new(participant2, 50_0000000));
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
 
-    let escrow = create_escrow(
-        &env,
-        String::from_str(&env, "escrow-001"),
-        creator.clone(),
-        String::from_str(&env, "Team dinner"),
-        100_0000000,
-        participants,
-        1735689600, // Some future timestamp
+    let mut shares = Vec::new(&env);
+    shares.push_back(i128::MAX);
+
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Huge split"),
+        &i128::MAX,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
     );
 
-    assert_eq!(escrow.total_amount, 100_0000000);
-    assert_eq!(escrow.amount_collected, 0);
-    assert_eq!(escrow.status, EscrowStatus::Active);
-    assert_eq!(escrow.creator, creator);
-    assert_eq!(escrow.participants.len(), 2);
+    // Corrupt the collected total so the next deposit would wrap
+    env.as_contract(&client.address, || {
+        let mut split = storage::get_split(&env, split_id);
+        split.amount_collected = i128::MAX - 10;
+        storage::set_split(&env, split_id, &split);
+    });
+
+    let result = client.try_deposit(&split_id, &participant, &20);
+    assert_eq!(result, Err(Ok(Error::Overflow)));
 }
 
 #[test]
-fn test_split_escrow_validation() {
+fn test_add_participant_payment_overflow() {
     let env = Env::default();
-    let creator = Address::generate(&env);
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+    let split_id = String::from_str(&env, "test-split");
     let participant = Address::generate(&env);
 
-    let mut participants = Vec::new(&env);
-    participants.push_back(EscrowParticipant {
-        address: participant,
-        amount_owed: 100,
-        amount_paid: 50,
-        paid_at: None,
+    env.as_contract(&contract_id, || {
+        storage::set_participant_payment(&env, &split_id, &participant, i128::MAX);
+
+        let result = storage::add_participant_payment(&env, &split_id, &participant, 1);
+        assert_eq!(result, Err(Error::Overflow));
+        assert_eq!(
+            storage::get_participant_payment(&env, &split_id, &participant),
+            i128::MAX
+        );
     });
-
-    // Valid escrow
-    let valid = SplitEscrow {
-        split_id: String::from_str(&env, "test-1"),
-        creator: creator.clone(),
-        description: String::from_str(&env, "Test"),
-        total_amount: 100,
-        amount_collected: 50,
-        participants: participants.clone(),
-        status: EscrowStatus::Active,
-        deadline: 99999999,
-        created_at: 1000,
-    };
-    assert!(valid.validate().is_ok());
-
-    // Collected exceeds total (invalid)
-    let over_collected = SplitEscrow {
-        split_id: String::from_str(&env, "test-2"),
-        creator: creator.clone(),
-        description: String::from_str(&env, "Test"),
-        total_amount: 100,
-        amount_collected: 150,
-        participants: participants.clone(),
-        status: EscrowStatus::Active,
-        deadline: 99999999,
-        created_at: 1000,
-    };
-    assert!(over_collected.validate().is_err());
 }
 
+// ============================================
+// Cancel Tests
+// ============================================
+
 #[test]
-fn test_split_escrow_expiry() {
-    let env = Env::default();
+fn test_cancel_split() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
     let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
 
-    let participants = Vec::new(&env);
+    let description = String::from_str(&env, "Test split");
 
-    let escrow = SplitEscrow {
-        split_id: String::from_str(&env, "test"),
-        creator,
-        description: String::from_str(&env, "Test"),
-        total_amount: 100,
-        amount_collected: 0,
-        participants,
-        status: EscrowStatus::Active,
-        deadline: 1000,
-        created_at: 500,
-    };
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant);
 
-    // Before deadline
-    assert!(!escrow.is_expired(999));
-    assert!(!escrow.is_expired(1000));
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
 
-    // After deadline
-    assert!(escrow.is_expired(1001));
-    assert!(escrow.is_expired(2000));
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+
+    let reason = String::from_str(&env, "Dinner was cancelled");
+    client.cancel_split(&split_id, &reason);
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Cancelled);
+    assert_eq!(split.cancel_reason, Some(reason.clone()));
+
+    let mut cancel_events = 0;
+    for (_, topics, data) in env.events().all().iter() {
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if topic == symbol_short!("cancel") {
+            let payload: (u64, String) = data.try_into_val(&env).unwrap();
+            assert_eq!(payload, (split_id, reason.clone()));
+            cancel_events += 1;
+        }
+    }
+    assert_eq!(cancel_events, 1);
+
+    // Reasons are bounded like descriptions
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+    let result = client.try_cancel_split(&split_id, &String::from_str(&env, ""));
+    assert_eq!(result, Err(Ok(Error::EmptyDescription)));
 }
 
 #[test]
-fn test_split_escrow_funding_helpers() {
-    let env = Env::default();
+fn test_cancel_split_requires_creator_auth() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
     let creator = Address::generate(&env);
-    let participants = Vec::new(&env);
+    let stranger = Address::generate(&env);
+    let participant = Address::generate(&env);
 
-    let partially_funded = SplitEscrow {
-        split_id: String::from_str(&env, "test"),
-        creator: creator.clone(),
-        description: String::from_str(&env, "Test"),
-        total_amount: 100,
-        amount_collected: 60,
-        participants: participants.clone(),
-        status: EscrowStatus::Active,
-        deadline: 99999999,
-        created_at: 1000,
-    };
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant);
 
-    assert!(!partially_funded.is_fully_funded());
-    assert_eq!(partially_funded.remaining_amount(), 40);
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
 
-    let fully_funded = SplitEscrow {
-        split_id: String::from_str(&env, "test"),
-        creator,
-        description: String::from_str(&env, "Test"),
-        total_amount: 100,
-        amount_collected: 100,
-        participants,
-        status: EscrowStatus::Completed,
-        deadline: 99999999,
-        created_at: 1000,
-    };
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
 
-    assert!(fully_funded.is_fully_funded());
-    assert_eq!(fully_funded.remaining_amount(), 0);
+    // Only the stranger signs, so the creator's auth check must fail
+    let reason = String::from_str(&env, "Not mine to cancel");
+    env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "cancel_split",
+            args: (split_id, reason.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    let result = client.try_cancel_split(&split_id, &reason);
+    assert!(result.is_err());
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Pending);
+}
+
+#[test]
+fn test_cancel_completed_split_fails() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+
+    let result = client.try_cancel_split(&split_id, &String::from_str(&env, "Too late"));
+    assert_eq!(result, Err(Ok(Error::CannotCancel)));
 }
 
 // ============================================
-// Enhanced Storage Tests (Issue #59)
+// Release Tests
 // ============================================
 
 #[test]
-fn test_escrow_count_storage() {
-    let env = Env::default();
-    let contract_id = env.register_contract(None, SplitEscrowContract);
+fn test_release_funds() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-    env.as_contract(&contract_id, || {
-        // Initial count should be 0
-        let initial = storage::get_escrow_count(&env);
-        assert_eq!(initial, 0);
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
 
-        // Increment should return new value
-        let first = storage::increment_escrow_count(&env);
-        assert_eq!(first, 1);
+    let description = String::from_str(&env, "Test split");
 
-        let second = storage::increment_escrow_count(&env);
-        assert_eq!(second, 2);
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
 
-        // Get should return current value
-        let current = storage::get_escrow_count(&env);
-        assert_eq!(current, 2);
-    });
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+
+    // Complete the split (auto-release should occur)
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Released);
+
+    let creator_balance = token_client.balance(&creator);
+    assert_eq!(creator_balance, 100_0000000);
+
+    // Manual release should be blocked after auto-release
+    let result = catch_unwind(AssertUnwindSafe(|| client.release_funds(&split_id)));
+    assert!(result.is_err());
 }
 
 #[test]
-fn test_escrow_storage() {
-    let env = Env::default();
-    let contract_id = env.register_contract(None, SplitEscrowContract);
-    let creator = Address::generate(&env);
-    let split_id = String::from_str(&env, "test-escrow-1");
+fn test_release_incomplete_split() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-    let participants = Vec::new(&env);
-    let escrow = create_escrow(
-        &env,
-        split_id.clone(),
-        creator.clone(),
-        String::from_str(&env, "Test escrow"),
-        1000,
-        participants,
-        99999999,
-    );
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
 
-    env.as_contract(&contract_id, || {
-        // Initially should not exist
-        assert!(!storage::has_escrow(&env, &split_id));
+    let description = String::from_str(&env, "Test split");
 
-        // Store and retrieve
-        storage::set_escrow(&env, &split_id, &escrow);
-        assert!(storage::has_escrow(&env, &split_id));
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant);
 
-        let retrieved = storage::get_escrow(&env, &split_id).unwrap();
-fn test_has_participant_payment() {
-    let env = Env::default();
-    let contract_id = env.register_contract(None, SplitEscrowContract);
-    let split_id = String::from_str(&env, "test-split");
-    let participant = Address::generate(&env);
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
 
-    env.as_contract(&contract_id, || {
-        // Initially should not exist (returns false because no explicit entry)
-        assert!(!storage::has_participant_payment(
-            &env,
-            &split_id,
-            &participant
-        ));
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
 
-        // After setting, should exist
-        storage::set_participant_payment(&env, &split_id, &participant, 100);
-        assert!(storage::has_participant_payment(
-            &env,
-            &split_id,
-            &participant
-        ));
-    });
+    // Try to release without completing deposits
+    let result = catch_unwind(AssertUnwindSafe(|| client.release_funds(&split_id)));
+    assert!(result.is_err());
 }
 
-// ============================================
-// Insurance Tests
-// ============================================
-
 #[test]
-fn test_insure_split_success() {
+fn test_release_requires_two_of_three_approvals() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let participant = Address::generate(&env);
+    let approver1 = Address::generate(&env);
+    let approver2 = Address::generate(&env);
+    let approver3 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "High value"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
     );
-    
-    // Mint tokens for the policy holder
-    let policy_holder = Address::generate(&env);
-    token_admin_client.mint(&policy_holder, &100);
-    
-    // Purchase insurance
-    let split_id_str = u64_to_string(&env, split_id);
-    let insurance_id = client.insure_split(&split_id_str, &10);
-    
-    // Verify insurance was created
-    assert!(insurance_id.len() > 0);
-    
-    // Check that insurance exists
-    let insurance = client.get_insurance(&insurance_id);
-    assert_eq!(insurance.split_id, split_id_str);
-    assert_eq!(insurance.policy_holder, policy_holder);
-    assert_eq!(insurance.premium, 10);
-    assert_eq!(insurance.coverage_amount, 100); // 10x premium
-    assert_eq!(insurance.status, types::InsuranceStatus::Active);
+
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver1.clone());
+    approvers.push_back(approver2.clone());
+    approvers.push_back(approver3.clone());
+    client.set_release_approvers(&split_id, &approvers, &2);
+
+    // Fully funding no longer auto-releases
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Completed);
+
+    let result = client.try_release_funds(&split_id);
+    assert_eq!(result, Err(Ok(Error::InsufficientApprovals)));
+
+    client.approve_release(&split_id, &approver1);
+    let result = client.try_approve_release(&split_id, &approver1);
+    assert_eq!(result, Err(Ok(Error::AlreadyApproved)));
+    let result = client.try_approve_release(&split_id, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotApprover)));
+
+    let result = client.try_release_funds(&split_id);
+    assert_eq!(result, Err(Ok(Error::InsufficientApprovals)));
+
+    client.approve_release(&split_id, &approver2);
+    client.release_funds(&split_id);
+
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Released);
+    assert_eq!(token_client.balance(&creator), 100_0000000);
 }
 
 #[test]
-fn test_insure_split_invalid_premium() {
+fn test_approvers_locked_after_first_approval() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let participant = Address::generate(&env);
+    let approver1 = Address::generate(&env);
+    let approver2 = Address::generate(&env);
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "Locked approvers"),
+        &100,
+        &Vec::from_array(&env, [participant.clone()]),
+        &Vec::from_array(&env, [100i128]),
+        &0,
+        &None,
+        &None,
+        &true,
     );
-    
-    // Try to purchase insurance with zero premium
-    let split_id_str = u64_to_string(&env, split_id);
-    let result = client.try_insure_split(&split_id_str, &0);
-    assert_eq!(result, Err(Ok(types::Error::InsufficientPremium)));
-}
 
-#[test]
-fn test_insure_split_nonexistent_split() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
-    initialize_contract(&client, &admin, &token_id);
-    
-    // Try to purchase insurance for non-existent split
-    let split_id_str = String::from_str(&env, "999");
-    let result = client.try_insure_split(&split_id_str, &10);
-    assert_eq!(result, Err(Ok(types::Error::SplitNotFound)));
+    // Reconfiguring is fine until someone approves
+    client.set_release_approvers(&split_id, &Vec::from_array(&env, [approver1.clone()]), &1);
+    let approvers = Vec::from_array(&env, [approver1.clone(), approver2.clone()]);
+    client.set_release_approvers(&split_id, &approvers, &2);
+
+    client.approve_release(&split_id, &approver1);
+
+    let result = client.try_set_release_approvers(&split_id, &Vec::from_array(&env, [approver2]), &1);
+    assert_eq!(result, Err(Ok(Error::ApproversLocked)));
+    let approvals = env.as_contract(&client.address, || {
+        storage::get_release_approvals(&env, split_id).unwrap().approvals
+    });
+    assert_eq!(approvals.len(), 1);
 }
 
 #[test]
-fn test_insure_split_already_insured() {
+fn test_release_funds_only_once() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let participant = Address::generate(&env);
+    let approver = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
     let split_id = client.create_split(
         &creator,
         &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
     );
-    
-    // Mint tokens for the policy holder
-    let policy_holder = Address::generate(&env);
-    token_admin_client.mint(&policy_holder, &100);
-    
-    // Purchase insurance first time
-    let split_id_str = u64_to_string(&env, split_id);
-    let _insurance_id = client.insure_split(&split_id_str, &10);
-    
-    // Try to purchase insurance again
-    let result = client.try_insure_split(&split_id_str, &10);
-    assert_eq!(result, Err(Ok(types::Error::InsuranceAlreadyExists)));
+
+    // Hold the split at Completed so release is a manual step
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver.clone());
+    client.set_release_approvers(&split_id, &approvers, &1);
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+    client.approve_release(&split_id, &approver);
+
+    client.release_funds(&split_id);
+    assert_eq!(token_client.balance(&creator), 100_0000000);
+
+    let split = client.get_split(&split_id);
+    assert!(split.released);
+    assert_eq!(split.status, SplitStatus::Released);
+
+    let result = client.try_release_funds(&split_id);
+    assert_eq!(result, Err(Ok(Error::AlreadyReleased)));
+    assert_eq!(token_client.balance(&creator), 100_0000000);
 }
 
 #[test]
-fn test_claim_insurance_success() {
+fn test_early_payment_bonus() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let sponsor = Address::generate(&env);
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(early.clone());
+    addresses.push_back(late.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(50_0000000i128);
+    shares.push_back(50_0000000i128);
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "Team lunch"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
     );
-    
-    // Mint tokens for the policy holder
-    let policy_holder = Address::generate(&env);
-    token_admin_client.mint(&policy_holder, &100);
-    
-    // Purchase insurance
-    let split_id_str = u64_to_string(&env, split_id);
-    let insurance_id = client.insure_split(&split_id_str, &10);
-    
-    // File a claim
-    let reason = String::from_str(&env, "Test claim reason");
-    client.claim_insurance(&insurance_id, &reason);
-    
-    // Verify claim was created (check events)
-    let events = env.events().all();
-    assert!(events.len() > 0);
+
+    client.set_bonus_deadline(&split_id, &1000);
+    token_admin_client.mint(&sponsor, &10_0000000i128);
+    client.fund_bonus(&split_id, &sponsor, &10_0000000);
+
+    token_admin_client.mint(&early, &50_0000000i128);
+    token_admin_client.mint(&late, &50_0000000i128);
+
+    env.ledger().with_mut(|l| l.timestamp = 500);
+    client.deposit(&split_id, &early, &50_0000000);
+
+    // Paying after the deadline completes the split but earns nothing
+    env.ledger().with_mut(|l| l.timestamp = 2000);
+    client.deposit(&split_id, &late, &50_0000000);
+
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Released);
+    assert_eq!(token_client.balance(&creator), 100_0000000);
+    assert_eq!(token_client.balance(&early), 10_0000000);
+    assert_eq!(token_client.balance(&late), 0);
+    assert_eq!(token_client.balance(&sponsor), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
 }
 
 #[test]
-fn test_claim_insurance_expired() {
-    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_bonus_needs_a_deadline() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let sponsor = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+    let mut shares = Vec::new(&env);
+    shares.push_back(50_0000000i128);
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "No bonus"),
+        &50_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &false,
     );
-    
-    // Mint tokens for the policy holder
-    let policy_holder = Address::generate(&env);
-    token_admin_client.mint(&policy_holder, &100);
-    
-    // Purchase insurance
-    let split_id_str = u64_to_string(&env, split_id);
-    let insurance_id = client.insure_split(&split_id_str, &10);
-    
-    // Fast forward time beyond expiration (31 days)
-    env.ledger().set_timestamp(env.ledger().timestamp() + (31 * 24 * 60 * 60));
-    
-    // Try to file a claim
-    let reason = String::from_str(&env, "Test claim reason");
-    let result = client.try_claim_insurance(&insurance_id, &reason);
-    assert_eq!(result, Err(Ok(types::Error::InsuranceExpired)));
+
+    token_admin_client.mint(&sponsor, &10_0000000i128);
+    let result = client.try_fund_bonus(&split_id, &sponsor, &10_0000000);
+    assert_eq!(result, Err(Ok(Error::NoBonusDeadline)));
+
+    // Paying at timestamp 0 doesn't beat a deadline that was never set
+    token_admin_client.mint(&participant, &50_0000000i128);
+    client.deposit(&split_id, &participant, &50_0000000);
+    let eligible = env.as_contract(&client.address, || storage::get_bonus_eligible(&env, split_id));
+    assert!(eligible.is_empty());
+}
+
+// ============================================
+// Partial Release and Funding Checks
+// ============================================
+
+#[test]
+fn test_is_fully_funded() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let description = String::from_str(&env, "Funding check");
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+
+    token_admin_client.mint(&participant, &50_0000000i128);
+    assert_eq!(client.deposit(&split_id, &participant, &50_0000000), 50_0000000);
+
+    let funded = client.is_fully_funded(&split_id);
+    assert!(!funded);
+
+    token_admin_client.mint(&participant, &50_0000000i128);
+    assert_eq!(client.deposit(&split_id, &participant, &50_0000000), 0);
+
+    let funded = client.is_fully_funded(&split_id);
+    assert!(funded);
 }
 
 #[test]
-fn test_process_claim_approved() {
+fn test_release_partial() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let participant = Address::generate(&env);
+
+    let description = String::from_str(&env, "Partial release");
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+
+    token_admin_client.mint(&participant, &60_0000000i128);
+    client.deposit(&split_id, &participant, &60_0000000);
+
+    let released = client.release_partial(&split_id);
+    assert_eq!(released, 60_0000000);
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Active);
+    assert_eq!(split.amount_released, 60_0000000);
+
+    let creator_balance = token_client.balance(&creator);
+    assert_eq!(creator_balance, 60_0000000);
+
+    token_admin_client.mint(&participant, &40_0000000i128);
+    client.deposit(&split_id, &participant, &40_0000000);
+
+    let split = client.get_split(&split_id);
+    assert_eq!(split.status, SplitStatus::Released);
+    assert_eq!(split.amount_released, 100_0000000);
+}
+
+#[test]
+fn test_get_releasable_after_partial_release() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let funded = Address::generate(&env);
+    let unfunded = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(funded.clone());
+    addresses.push_back(unfunded.clone());
+    let mut shares = Vec::new(&env);
+    shares.push_back(50_0000000i128);
+    shares.push_back(50_0000000i128);
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "Releasable"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
     );
-    
-    // Mint tokens for the policy holder
-    let policy_holder = Address::generate(&env);
-    token_admin_client.mint(&policy_holder, &100);
-    
-    // Purchase insurance
-    let split_id_str = u64_to_string(&env, split_id);
-    let insurance_id = client.insure_split(&split_id_str, &10);
-    
-    // File a claim
-    let reason = String::from_str(&env, "Test claim reason");
-    client.claim_insurance(&insurance_id, &reason);
-    
-    // Get claim ID from insurance claims
-    let claim_ids = client.get_insurance_claims(&insurance_id);
-    assert_eq!(claim_ids.len(), 1);
-    let claim_id = claim_ids.get(0).unwrap();
-    
-    // Process claim as admin (approve)
-    client.process_claim(&claim_id, &true);
-    
-    // Verify claim was processed (check events)
-    let events = env.events().all();
-    assert!(events.len() > 0);
+
+    token_admin_client.mint(&funded, &50_0000000i128);
+    client.deposit(&split_id, &funded, &30_0000000);
+    assert_eq!(client.get_releasable(&split_id, &funded), 30_0000000);
+
+    client.release_partial(&split_id);
+    assert_eq!(client.get_releasable(&split_id, &funded), 0);
+
+    // Only what was paid after the release is outstanding
+    client.deposit(&split_id, &funded, &10_0000000);
+    assert_eq!(client.get_releasable(&split_id, &funded), 10_0000000);
+    assert_eq!(client.get_releasable(&split_id, &unfunded), 0);
+
+    let result = client.try_get_releasable(&split_id, &creator);
+    assert_eq!(result, Err(Ok(Error::ParticipantNotFound)));
+}
+
+#[test]
+fn test_create_split_idempotency_key() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(Address::generate(&env));
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let description = String::from_str(&env, "Retried");
+    let key = Some(BytesN::from_array(&env, &[7u8; 32]));
+
+    let first = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &key, &true);
+    let retry = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &key, &true);
+    assert_eq!(first, retry);
+
+    let count = env.as_contract(&client.address, || storage::get_split_count(&env));
+    assert_eq!(count, 1);
+
+    // The key is scoped to its creator
+    let other = Address::generate(&env);
+    let second = client.create_split(&other, &description, &100_0000000, &addresses, &shares, &0, &None, &key, &true);
+    assert_ne!(second, first);
 }
 
 #[test]
-fn test_process_claim_rejected() {
+fn test_release_goes_to_payout_recipient() {
     let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let recipient = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "Payout"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &Some(recipient.clone()),
+        &None,
+        &true,
     );
-    
-    // Mint tokens for the policy holder
-    let policy_holder = Address::generate(&env);
-    token_admin_client.mint(&policy_holder, &100);
-    
-    // Purchase insurance
-    let split_id_str = u64_to_string(&env, split_id);
-    let insurance_id = client.insure_split(&split_id_str, &10);
-    
-    // File a claim
-    let reason = String::from_str(&env, "Test claim reason");
-    client.claim_insurance(&insurance_id, &reason);
-    
-    // Get claim ID from insurance claims
-    let claim_ids = client.get_insurance_claims(&insurance_id);
-    assert_eq!(claim_ids.len(), 1);
-    let claim_id = claim_ids.get(0).unwrap();
-    
-    // Process claim as admin (reject)
-    client.process_claim(&claim_id, &false);
-    
-    // Verify claim was processed (check events)
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &40_0000000);
+    client.release_partial(&split_id);
+    assert_eq!(token_client.balance(&recipient), 40_0000000);
+
+    // Redirecting back to the creator applies to later releases only
+    client.set_payout_recipient(&split_id, &None);
+    client.deposit(&split_id, &participant, &60_0000000);
+
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Released);
+    assert_eq!(token_client.balance(&recipient), 40_0000000);
+    assert_eq!(token_client.balance(&creator), 60_0000000);
+
+    let result = client.try_set_payout_recipient(&split_id, &Some(recipient));
+    assert_eq!(result, Err(Ok(Error::SplitReleased)));
+}
+
+// ============================================
+// Event Emission Tests
+// ============================================
+
+#[test]
+fn test_events_emitted_on_auto_release() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let description = String::from_str(&env, "Event check");
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(&creator, &description, &100_0000000, &addresses, &shares, &0, &None, &None, &true);
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+
     let events = env.events().all();
-    assert!(events.len() > 0);
+    let mut has_completed = false;
+    let mut has_released = false;
+
+    for i in 0..events.len() {
+        let event = events.get(i).unwrap();
+        let topics = &event.1;
+        let data = &event.2;
+
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if topic == symbol_short!("completed") {
+            let payload: (u64, i128) = data.try_into_val(&env).unwrap();
+            assert_eq!(payload.0, split_id);
+            assert_eq!(payload.1, 100_0000000);
+            has_completed = true;
+        }
+        if topic == symbol_short!("released") {
+            let payload: (u64, Address, i128, u64) = data.try_into_val(&env).unwrap();
+            assert_eq!(payload.0, split_id);
+            assert_eq!(payload.1, creator);
+            assert_eq!(payload.2, 100_0000000);
+            has_released = true;
+        }
+    }
+
+    assert!(has_completed);
+    assert!(has_released);
 }
 
 #[test]
-fn test_insurance_storage_helpers() {
-    let env = Env::default();
-    let contract_id = env.register_contract(None, SplitEscrowContract);
-    
-    let insurance_id = String::from_str(&env, "test-insurance");
+fn test_status_changed_events_in_order() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let approver = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Lifecycle"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+
+    // Hold the split at Completed so the lifecycle stops there
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(approver);
+    client.set_release_approvers(&split_id, &approvers, &1);
+
+    env.ledger().with_mut(|l| l.timestamp = 5000);
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit(&split_id, &participant, &100_0000000);
+
+    let mut transitions = std::vec::Vec::new();
+    for (_, topics, data) in env.events().all().iter() {
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if topic == Symbol::new(&env, "status_changed") {
+            let payload: (u64, SplitStatus, SplitStatus, u64) = data.try_into_val(&env).unwrap();
+            assert_eq!(payload.0, split_id);
+            assert_eq!(payload.3, 5000);
+            transitions.push((payload.1, payload.2));
+        }
+    }
+
+    assert_eq!(
+        transitions,
+        std::vec![
+            (SplitStatus::Pending, SplitStatus::Active),
+            (SplitStatus::Active, SplitStatus::Completed),
+        ]
+    );
+}
+
+#[test]
+fn test_first_nonzero_deposit_activates_split() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Activation"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    let result = client.try_deposit(&split_id, &participant, &0);
+    assert_eq!(result, Err(Ok(Error::ZeroDeposit)));
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Pending);
+
+    client.deposit(&split_id, &participant, &10_0000000);
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Active);
+
+    let mut transitions = std::vec::Vec::new();
+    for (_, topics, data) in env.events().all().iter() {
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if topic == Symbol::new(&env, "status_changed") {
+            let payload: (u64, SplitStatus, SplitStatus, u64) = data.try_into_val(&env).unwrap();
+            transitions.push((payload.1, payload.2));
+        }
+    }
+    assert_eq!(transitions, std::vec![(SplitStatus::Pending, SplitStatus::Active)]);
+}
+
+// ============================================
+// Enhanced Escrow Data Structure Tests (Issue #59)
+// ============================================
+
+#[test]
+fn test_escrow_status_values() {
+    // I'm verifying that all EscrowStatus variants are distinct and usable
+    let active = EscrowStatus::Active;
+    let completed = EscrowStatus::Completed;
+    let cancelled = EscrowStatus::Cancelled;
+    let expired = EscrowStatus::Expired;
+
+    assert_eq!(active, EscrowStatus::Active);
+    assert_ne!(active, completed);
+    assert_ne!(completed, cancelled);
+    assert_ne!(cancelled, expired);
+}
+
+#[test]
+fn test_escrow_participant_creation() {
+    let env = Env::default();
+    let address = Address::generate(&env);
+
+    let participant = EscrowParticipant::new(address.clone(), 100_0000000);
+
+    assert_eq!(participant.address, address);
+    assert_eq!(participant.amount_owed, 100_0000000);
+    assert_eq!(participant.amount_paid, 0);
+    assert!(participant.paid_at.is_none());
+}
+
+#[test]
+fn test_escrow_participant_validation() {
+    let env = Env::default();
+    let address = Address::generate(&env);
+
+    // Valid participant
+    let valid = EscrowParticipant {
+        address: address.clone(),
+        amount_owed: 100,
+        amount_paid: 50,
+        paid_at: None,
+    };
+    assert!(valid.validate().is_ok());
+
+    // Overpaid participant (invalid)
+    let overpaid = EscrowParticipant {
+        address: address.clone(),
+        amount_owed: 100,
+        amount_paid: 150,
+        paid_at: None,
+    };
+    assert!(overpaid.validate().is_err());
+
+    // Negative amount (invalid)
+    let negative = EscrowParticipant {
+        address: address.clone(),
+        amount_owed: -100,
+        amount_paid: 0,
+        paid_at: None,
+    };
+    assert!(negative.validate().is_err());
+}
+
+#[test]
+fn test_escrow_participant_helpers() {
+    let env = Env::default();
+    let address = Address::generate(&env);
+
+    let participant = EscrowParticipant {
+        address: address.clone(),
+        amount_owed: 100,
+        amount_paid: 60,
+        paid_at: None,
+    };
+
+    assert!(!participant.has_fully_paid());
+    assert_eq!(participant.remaining_owed(), 40);
+
+    let fully_paid = EscrowParticipant {
+        address: address.clone(),
+        amount_owed: 100,
+        amount_paid: 100,
+        paid_at: Some(12345),
+    };
+
+    assert!(fully_paid.has_fully_paid());
+    assert_eq!(fully_paid.remaining_owed(), 0);
+}
+
+#[test]
+fn test_split_escrow_creation() {
+    let env = Env::default();
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+
+    let mut participants = Vec::new(&env);
+    participants.push_back(EscrowParticipant::new(participant1, 50_0000000));
+    participants.push_back(EscrowParticipant::new(participant2, 50_0000000));
+
+    let escrow = create_escrow(
+        &env,
+        String::from_str(&env, "escrow-001"),
+        creator.clone(),
+        String::from_str(&env, "Team dinner"),
+        100_0000000,
+        participants,
+        1735689600, // Some future timestamp
+    );
+
+    assert_eq!(escrow.total_amount, 100_0000000);
+    assert_eq!(escrow.amount_collected, 0);
+    assert_eq!(escrow.status, EscrowStatus::Active);
+    assert_eq!(escrow.creator, creator);
+    assert_eq!(escrow.participants.len(), 2);
+}
+
+#[test]
+fn test_split_escrow_validation() {
+    let env = Env::default();
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut participants = Vec::new(&env);
+    participants.push_back(EscrowParticipant {
+        address: participant,
+        amount_owed: 100,
+        amount_paid: 50,
+        paid_at: None,
+    });
+
+    // Valid escrow
+    let valid = SplitEscrow {
+        split_id: String::from_str(&env, "test-1"),
+        creator: creator.clone(),
+        description: String::from_str(&env, "Test"),
+        total_amount: 100,
+        amount_collected: 50,
+        participants: participants.clone(),
+        status: EscrowStatus::Active,
+        deadline: 99999999,
+        created_at: 1000,
+        grace_period_secs: 0,
+        rounding_beneficiary: None,
+    };
+    assert!(valid.validate().is_ok());
+
+    // Collected exceeds total (invalid)
+    let over_collected = SplitEscrow {
+        split_id: String::from_str(&env, "test-2"),
+        creator: creator.clone(),
+        description: String::from_str(&env, "Test"),
+        total_amount: 100,
+        amount_collected: 150,
+        participants: participants.clone(),
+        status: EscrowStatus::Active,
+        deadline: 99999999,
+        created_at: 1000,
+        grace_period_secs: 0,
+        rounding_beneficiary: None,
+    };
+    assert!(over_collected.validate().is_err());
+}
+
+#[test]
+fn test_split_escrow_expiry() {
+    let env = Env::default();
+    let creator = Address::generate(&env);
+
+    let participants = Vec::new(&env);
+
+    let escrow = SplitEscrow {
+        split_id: String::from_str(&env, "test"),
+        creator,
+        description: String::from_str(&env, "Test"),
+        total_amount: 100,
+        amount_collected: 0,
+        participants,
+        status: EscrowStatus::Active,
+        deadline: 1000,
+        created_at: 500,
+        grace_period_secs: 0,
+        rounding_beneficiary: None,
+    };
+
+    // Before deadline
+    assert!(!escrow.is_expired(999));
+    assert!(!escrow.is_expired(1000));
+
+    // After deadline
+    assert!(escrow.is_expired(1001));
+    assert!(escrow.is_expired(2000));
+}
+
+#[test]
+fn test_split_escrow_funding_helpers() {
+    let env = Env::default();
+    let creator = Address::generate(&env);
+    let participants = Vec::new(&env);
+
+    let partially_funded = SplitEscrow {
+        split_id: String::from_str(&env, "test"),
+        creator: creator.clone(),
+        description: String::from_str(&env, "Test"),
+        total_amount: 100,
+        amount_collected: 60,
+        participants: participants.clone(),
+        status: EscrowStatus::Active,
+        deadline: 99999999,
+        created_at: 1000,
+        grace_period_secs: 0,
+        rounding_beneficiary: None,
+    };
+
+    assert!(!partially_funded.is_fully_funded());
+    assert_eq!(partially_funded.remaining_amount(), 40);
+
+    let fully_funded = SplitEscrow {
+        split_id: String::from_str(&env, "test"),
+        creator,
+        description: String::from_str(&env, "Test"),
+        total_amount: 100,
+        amount_collected: 100,
+        participants,
+        status: EscrowStatus::Completed,
+        deadline: 99999999,
+        created_at: 1000,
+        grace_period_secs: 0,
+        rounding_beneficiary: None,
+    };
+
+    assert!(fully_funded.is_fully_funded());
+    assert_eq!(fully_funded.remaining_amount(), 0);
+}
+
+// ============================================
+// Enhanced Storage Tests (Issue #59)
+// ============================================
+
+#[test]
+fn test_escrow_count_storage() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+
+    env.as_contract(&contract_id, || {
+        // Initial count should be 0
+        let initial = storage::get_escrow_count(&env);
+        assert_eq!(initial, 0);
+
+        // Increment should return new value
+        let first = storage::increment_escrow_count(&env);
+        assert_eq!(first, 1);
+
+        let second = storage::increment_escrow_count(&env);
+        assert_eq!(second, 2);
+
+        // Get should return current value
+        let current = storage::get_escrow_count(&env);
+        assert_eq!(current, 2);
+    });
+}
+
+#[test]
+fn test_escrow_storage() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+    let creator = Address::generate(&env);
+    let split_id = String::from_str(&env, "test-escrow-1");
+
+    let participants = Vec::new(&env);
+    let escrow = create_escrow(
+        &env,
+        split_id.clone(),
+        creator.clone(),
+        String::from_str(&env, "Test escrow"),
+        1000,
+        participants,
+        99999999,
+    );
+
+    env.as_contract(&contract_id, || {
+        // Initially should not exist
+        assert!(!storage::has_escrow(&env, &split_id));
+
+        // Store and retrieve
+        storage::set_escrow(&env, &split_id, &escrow);
+        assert!(storage::has_escrow(&env, &split_id));
+
+        let retrieved = storage::get_escrow(&env, &split_id).unwrap();
+        assert_eq!(retrieved.split_id, split_id);
+        assert_eq!(retrieved.creator, creator);
+        assert_eq!(retrieved.total_amount, 1000);
+        assert_eq!(retrieved.deadline, 99999999);
+    });
+}
+
+#[test]
+fn test_has_participant_payment() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+    let split_id = String::from_str(&env, "test-split");
+    let participant = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        // Initially should not exist (returns false because no explicit entry)
+        assert!(!storage::has_participant_payment(
+            &env,
+            &split_id,
+            &participant
+        ));
+
+        // After setting, should exist
+        storage::set_participant_payment(&env, &split_id, &participant, 100);
+        assert!(storage::has_participant_payment(
+            &env,
+            &split_id,
+            &participant
+        ));
+    });
+}
+
+// ============================================
+// Insurance Tests
+// ============================================
+
+#[test]
+fn test_insurance_storage_helpers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+    
+    let insurance_id = String::from_str(&env, "test-insurance");
     let split_id = String::from_str(&env, "test-split");
     let policy_holder = Address::generate(&env);
     
-    let policy = types::InsurancePolicy {
-        insurance_id: insurance_id.clone(),
-        split_id: split_id.clone(),
-        policy_holder: policy_holder.clone(),
-        premium: 10,
-        coverage_amount: 100,
-        status: types::InsuranceStatus::Active,
-        created_at: 12345,
-        expires_at: 12345 + (30 * 24 * 60 * 60),
-    };
+    let policy = types::InsurancePolicy {
+        insurance_id: insurance_id.clone(),
+        split_id: split_id.clone(),
+        policy_holder: policy_holder.clone(),
+        premium: 10,
+        coverage_amount: 100,
+        status: types::InsuranceStatus::Active,
+        created_at: 12345,
+        expires_at: 12345 + (30 * 24 * 60 * 60),
+    };
+    
+    env.as_contract(&contract_id, || {
+        // Test insurance storage
+        assert!(!storage::has_insurance(&env, &insurance_id));
+        
+        storage::set_insurance(&env, &insurance_id, &policy);
+        assert!(storage::has_insurance(&env, &insurance_id));
+        
+        let retrieved = storage::get_insurance(&env, &insurance_id);
+        assert_eq!(retrieved.insurance_id, insurance_id);
+        assert_eq!(retrieved.split_id, split_id);
+        assert_eq!(retrieved.policy_holder, policy_holder);
+        
+        // Test split to insurance mapping
+        assert!(!storage::has_split_insurance(&env, &split_id));
+        
+        storage::set_split_to_insurance(&env, &split_id, &insurance_id);
+        assert!(storage::has_split_insurance(&env, &split_id));
+        
+        let mapped_insurance_id = storage::get_split_to_insurance(&env, &split_id);
+        assert_eq!(mapped_insurance_id, Some(insurance_id.clone()));
+        
+        // Test claim storage
+        let claim_id = String::from_str(&env, "test-claim");
+        let claim = types::InsuranceClaim {
+            claim_id: claim_id.clone(),
+            insurance_id: insurance_id.clone(),
+            claimant: policy_holder.clone(),
+            reason: String::from_str(&env, "test reason"),
+            claim_amount: 50,
+            status: types::ClaimStatus::Pending,
+            filed_at: 12345,
+            processed_at: None,
+            notes: None,
+        };
+        
+        assert!(!storage::has_claim(&env, &claim_id));
+        
+        storage::set_claim(&env, &claim_id, &claim);
+        assert!(storage::has_claim(&env, &claim_id));
+        
+        let retrieved_claim = storage::get_claim(&env, &claim_id);
+        assert_eq!(retrieved_claim.claim_id, claim_id);
+        assert_eq!(retrieved_claim.insurance_id, insurance_id);
+        
+        // Test insurance claims mapping
+        storage::add_insurance_claim(&env, &insurance_id, &claim_id);
+        let claim_ids = storage::get_insurance_claims(&env, &insurance_id);
+        assert_eq!(claim_ids.len(), 1);
+        assert_eq!(claim_ids.get(0).unwrap(), claim_id);
+    });
+}
+
+// ============================================
+// Rewards Tests
+// ============================================
+
+#[test]
+fn test_track_split_usage_success() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    let user = Address::generate(&env);
+    
+    // Track split usage
+    client.track_split_usage(&user);
+    
+    // Verify user rewards data was created
+    let rewards = env.as_contract(&client.address, || storage::get_user_rewards(&env, &user)).unwrap();
+    assert!(rewards.total_splits_participated >= 1);
+    assert_eq!(rewards.user, user);
+    
+    // Check events
+    let events = env.events().all();
+    assert!(!events.is_empty());
+}
+
+#[test]
+fn test_calculate_rewards_new_user() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    let user = Address::generate(&env);
+    
+    // Calculate rewards for new user
+    let rewards_amount = client.calculate_rewards(&user);
+    
+    // Should be 0 for new user
+    assert_eq!(rewards_amount, 0);
+    
+    // Verify rewards info
+    let rewards_info = env.as_contract(&client.address, || storage::get_user_rewards(&env, &user)).unwrap();
+    assert_eq!(rewards_info.rewards_earned, 0);
+    assert_eq!(rewards_info.rewards_claimed, 0);
+}
+
+#[test]
+fn test_calculate_rewards_active_user() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    let user = Address::generate(&env);
+    
+    // First, create some user rewards data manually
+    env.as_contract(&client.address, || {
+        let rewards = types::UserRewards {
+            user: user.clone(),
+            total_splits_created: 5,
+            total_splits_participated: 10,
+            total_amount_transacted: 1000,
+            rewards_earned: 0,
+            rewards_claimed: 0,
+            last_activity: env.ledger().timestamp(),
+            status: types::RewardsStatus::Active,
+        };
+        
+        // Store manually for testing
+        let key = storage::RewardsStorageKey::UserRewards(user.clone());
+        env.storage().persistent().set(&key, &rewards);
+    });
+    
+    // Calculate rewards
+    let rewards_amount = client.calculate_rewards(&user);
+    
+    // Expected: 5*10 + 10*5 + 1000/1000 = 50 + 50 + 1 = 101
+    assert_eq!(rewards_amount, 101);
+    
+    // Verify rewards info was updated
+    let rewards_info = env.as_contract(&client.address, || storage::get_user_rewards(&env, &user)).unwrap();
+    assert_eq!(rewards_info.rewards_earned, 101);
+}
+
+#[test]
+fn test_claim_rewards_success() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    let user = Address::generate(&env);
+    
+    // Set up user with earned rewards
+    env.as_contract(&client.address, || {
+        let rewards = types::UserRewards {
+            user: user.clone(),
+            total_splits_created: 2,
+            total_splits_participated: 3,
+            total_amount_transacted: 500,
+            rewards_earned: 50, // 2*10 + 3*5 + 500/1000 = 20 + 15 + 0 = 35 (let's say 50 for testing)
+            rewards_claimed: 0,
+            last_activity: env.ledger().timestamp(),
+            status: types::RewardsStatus::Active,
+        };
+        
+        let key = storage::RewardsStorageKey::UserRewards(user.clone());
+        env.storage().persistent().set(&key, &rewards);
+    });
+    
+    // Claim rewards
+    let claimed_amount = client.claim_rewards(&user);
+    assert_eq!(claimed_amount, 50);
+    
+    // Verify rewards info was updated
+    let rewards_info = env.as_contract(&client.address, || storage::get_user_rewards(&env, &user)).unwrap();
+    assert_eq!(rewards_info.rewards_claimed, 50);
+    
+    // Check events
+    let events = env.events().all();
+    assert!(!events.is_empty());
+}
+
+#[test]
+fn test_claim_rewards_insufficient_rewards() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    let user = Address::generate(&env);
+    
+    // Set up user with no earned rewards
+    env.as_contract(&client.address, || {
+        let rewards = types::UserRewards {
+            user: user.clone(),
+            total_splits_created: 0,
+            total_splits_participated: 0,
+            total_amount_transacted: 0,
+            rewards_earned: 0,
+            rewards_claimed: 0,
+            last_activity: env.ledger().timestamp(),
+            status: types::RewardsStatus::Active,
+        };
+        
+        let key = storage::RewardsStorageKey::UserRewards(user.clone());
+        env.storage().persistent().set(&key, &rewards);
+    });
+    
+    // Try to claim rewards
+    let result = client.try_claim_rewards(&user);
+    assert_eq!(result, Err(Ok(types::Error::InsufficientRewards)));
+}
+
+#[test]
+fn test_claim_rewards_unauthorized_user() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    
+    // Set up user1 with rewards
+    env.as_contract(&client.address, || {
+        let rewards = types::UserRewards {
+            user: user1.clone(),
+            total_splits_created: 1,
+            total_splits_participated: 1,
+            total_amount_transacted: 100,
+            rewards_earned: 25,
+            rewards_claimed: 0,
+            last_activity: env.ledger().timestamp(),
+            status: types::RewardsStatus::Active,
+        };
+        
+        let key = storage::RewardsStorageKey::UserRewards(user1.clone());
+        env.storage().persistent().set(&key, &rewards);
+    });
+    
+    // Try user2 to claim user1's rewards; only user2 has authorized
+    env.mock_auths(&[MockAuth {
+        address: &user2,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "claim_rewards",
+            args: (user1.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    let result = client.try_claim_rewards(&user1);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_rewards_storage_helpers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+    
+    let user = Address::generate(&env);
+    
+    env.as_contract(&contract_id, || {
+        // Test storing and retrieving user rewards
+        let rewards = types::UserRewards {
+            user: user.clone(),
+            total_splits_created: 3,
+            total_splits_participated: 5,
+            total_amount_transacted: 750,
+            rewards_earned: 85,
+            rewards_claimed: 25,
+            last_activity: env.ledger().timestamp(),
+            status: types::RewardsStatus::Active,
+        };
+        
+        // Store rewards
+        storage::set_user_rewards(&env, &user, &rewards);
+        
+        // Verify storage
+        assert!(storage::has_user_rewards(&env, &user));
+        
+        let retrieved = storage::get_user_rewards(&env, &user).unwrap();
+        assert_eq!(retrieved.total_splits_created, 3);
+        assert_eq!(retrieved.total_splits_participated, 5);
+        assert_eq!(retrieved.rewards_earned, 85);
+        assert_eq!(retrieved.rewards_claimed, 25);
+        
+        // Test activity storage
+        let activity_id = storage::get_next_activity_id(&env);
+        let activity = types::UserActivity {
+            user: user.clone(),
+            activity_type: types::ActivityType::SplitCreated,
+            split_id: 123,
+            amount: 100,
+            timestamp: env.ledger().timestamp(),
+        };
+        
+        storage::set_user_activity(&env, &user, activity_id, &activity);
+        
+        let retrieved_activity = storage::get_user_activity(&env, &user, activity_id).unwrap();
+        assert_eq!(retrieved_activity.split_id, 123);
+        assert_eq!(retrieved_activity.amount, 100);
+    });
+}
+
+// ============================================
+// Oracle Tests
+// ============================================
+
+#[test]
+fn test_submit_verification_success() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Create a split first
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &1000,
+        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
+        &Vec::from_array(&env, [500i128, 500i128]),
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    
+    // Submit verification
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let verification_id = client.submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    
+    // Verify verification was created
+    assert!(!verification_id.is_empty());
+    
+    // Check verification request
+    let verification = env.as_contract(&client.address, || storage::get_verification_request(&env, &verification_id)).unwrap();
+    assert_eq!(verification.split_id, u64_to_string(&env, split_id));
+    assert_eq!(verification.requester, creator);
+    assert_eq!(verification.receipt_hash, receipt_hash);
+    assert_eq!(verification.status, types::VerificationStatus::Pending);
+    
+    // Check events
+    let events = env.events().all();
+    assert!(!events.is_empty());
+}
+
+#[test]
+fn test_submit_verification_nonexistent_split() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Try to submit verification for non-existent split
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let requester = Address::generate(&env);
+    let result =
+        client.try_submit_verification(&requester, &String::from_str(&env, "999"), &receipt_hash);
+    assert_eq!(result, Err(Ok(types::Error::SplitNotFound)));
+}
+
+#[test]
+fn test_submit_verification_already_exists() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Create a split first
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &1000,
+        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
+        &Vec::from_array(&env, [500i128, 500i128]),
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    
+    // Submit first verification
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let _verification_id = client.submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    
+    // Try to submit second verification
+    let result = client.try_submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    assert_eq!(result, Err(Ok(types::Error::VerificationAlreadyExists)));
+}
+
+#[test]
+fn test_verify_split_success() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Create a split and submit verification
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &1000,
+        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
+        &Vec::from_array(&env, [500i128, 500i128]),
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let verification_id = client.submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    
+    // Set up oracle config to allow verification
+    let oracle = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let config = types::OracleConfig {
+            required_verifications: 1,
+            verification_timeout: 86400, // 24 hours
+            min_oracles: 1,
+            oracle_addresses: Vec::from_array(&env, [oracle.clone()]),
+        };
+        storage::set_oracle_config(&env, &config);
+    });
+    
+    // Verify split as oracle
+    client.verify_split(&oracle, &verification_id, &true);
+    
+    // Check verification was updated
+    let verification = env.as_contract(&client.address, || storage::get_verification_request(&env, &verification_id)).unwrap();
+    assert_eq!(verification.status, types::VerificationStatus::Verified);
+    assert_eq!(verification.verified_by, Some(oracle));
+    assert!(verification.verified_at.is_some());
+    
+    // Check events
+    let events = env.events().all();
+    assert!(!events.is_empty());
+}
+
+#[test]
+fn test_verify_split_unauthorized() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Create a split and submit verification
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &1000,
+        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
+        &Vec::from_array(&env, [500i128, 500i128]),
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let verification_id = client.submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    
+    // Try to verify split as non-oracle
+    let unauthorized = Address::generate(&env);
+    let result = client.try_verify_split(&unauthorized, &verification_id, &true);
+    assert_eq!(result, Err(Ok(types::Error::OracleNotAuthorized)));
+}
+
+#[test]
+fn test_verify_split_invalid_status() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Create a split and submit verification
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &1000,
+        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
+        &Vec::from_array(&env, [500i128, 500i128]),
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let verification_id = client.submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    
+    // Set up oracle config and verify first time
+    let oracle = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let config = types::OracleConfig {
+            required_verifications: 1,
+            verification_timeout: 86400,
+            min_oracles: 1,
+            oracle_addresses: Vec::from_array(&env, [oracle.clone()]),
+        };
+        storage::set_oracle_config(&env, &config);
+    });
+    
+    client.verify_split(&oracle, &verification_id, &true);
+    
+    // Try to verify again (should fail - already verified)
+    let result = client.try_verify_split(&oracle, &verification_id, &true);
+    assert_eq!(result, Err(Ok(types::Error::InvalidVerificationStatus)));
+}
+
+#[test]
+fn test_get_verification_status() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    
+    // Initialize contract
+    initialize_contract(&client, &admin, &token_id);
+    
+    // Create a split
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    
+    let split_id = client.create_split(
+        &creator,
+        &String::from_str(&env, "Test split"),
+        &1000,
+        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
+        &Vec::from_array(&env, [500i128, 500i128]),
+        &0,
+        &None,
+        &None,
+        &true,
+    );
+    
+    // No verifications yet - should return Pending
+    let status = client.get_verification_status(&u64_to_string(&env, split_id));
+    assert_eq!(status, types::VerificationStatus::Pending);
+    
+    // Submit and verify a split
+    let receipt_hash = String::from_str(&env, "receipt_hash_123");
+    let verification_id = client.submit_verification(&creator, &u64_to_string(&env, split_id), &receipt_hash);
+    
+    let oracle = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        let config = types::OracleConfig {
+            required_verifications: 1,
+            verification_timeout: 86400,
+            min_oracles: 1,
+            oracle_addresses: Vec::from_array(&env, [oracle.clone()]),
+        };
+        storage::set_oracle_config(&env, &config);
+    });
+    
+    client.verify_split(&oracle, &verification_id, &true);
+    
+    // Should now return Verified
+    let status = client.get_verification_status(&u64_to_string(&env, split_id));
+    assert_eq!(status, types::VerificationStatus::Verified);
+}
+
+#[test]
+fn test_oracle_storage_helpers() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, SplitEscrowContract);
+    
+    let verification_id = String::from_str(&env, "test-verification");
+    let split_id = String::from_str(&env, "test-split");
+    let requester = Address::generate(&env);
     
     env.as_contract(&contract_id, || {
-        // Test insurance storage
-        assert!(!storage::has_insurance(&env, &insurance_id));
-        
-        storage::set_insurance(&env, &insurance_id, &policy);
-        assert!(storage::has_insurance(&env, &insurance_id));
-        
-        let retrieved = storage::get_insurance(&env, &insurance_id);
-        assert_eq!(retrieved.insurance_id, insurance_id);
-        assert_eq!(retrieved.split_id, split_id);
-        assert_eq!(retrieved.policy_holder, policy_holder);
+        // Test storing and retrieving verification request
+        let request = types::VerificationRequest {
+            verification_id: verification_id.clone(),
+            split_id: split_id.clone(),
+            requester: requester.clone(),
+            receipt_hash: String::from_str(&env, "receipt_hash"),
+            evidence_url: Some(String::from_str(&env, "https://example.com/evidence")),
+            submitted_at: 12345,
+            status: types::VerificationStatus::Pending,
+            verified_by: None,
+            verified_at: None,
+            rejection_reason: None,
+        };
         
-        // Test split to insurance mapping
-        assert!(!storage::has_split_insurance(&env, &split_id));
+        // Store verification request
+        storage::set_verification_request(&env, &verification_id, &request);
         
-        storage::set_split_to_insurance(&env, &split_id, &insurance_id);
-        assert!(storage::has_split_insurance(&env, &split_id));
+        // Verify storage
+        assert!(storage::has_verification_request(&env, &verification_id));
         
-        let mapped_insurance_id = storage::get_split_to_insurance(&env, &split_id);
-        assert_eq!(mapped_insurance_id, Some(insurance_id.clone()));
+        let retrieved = storage::get_verification_request(&env, &verification_id).unwrap();
+        assert_eq!(retrieved.verification_id, verification_id);
+        assert_eq!(retrieved.split_id, split_id);
+        assert_eq!(retrieved.requester, requester);
+        assert_eq!(retrieved.receipt_hash, String::from_str(&env, "receipt_hash"));
         
-        // Test claim storage
-        let claim_id = String::from_str(&env, "test-claim");
-        let claim = types::InsuranceClaim {
-            claim_id: claim_id.clone(),
-            insurance_id: insurance_id.clone(),
-            claimant: policy_holder.clone(),
-            reason: String::from_str(&env, "test reason"),
-            claim_amount: 50,
-            status: types::ClaimStatus::Pending,
-            filed_at: 12345,
-            processed_at: None,
-            notes: None,
+        // Test oracle config storage
+        let config = types::OracleConfig {
+            required_verifications: 3,
+            verification_timeout: 172800, // 48 hours
+            min_oracles: 2,
+            oracle_addresses: Vec::from_array(&env, [requester.clone(), Address::generate(&env)]),
         };
         
-        assert!(!storage::has_claim(&env, &claim_id));
-        
-        storage::set_claim(&env, &claim_id, &claim);
-        assert!(storage::has_claim(&env, &claim_id));
-        
-        let retrieved_claim = storage::get_claim(&env, &claim_id);
-        assert_eq!(retrieved_claim.claim_id, claim_id);
-        assert_eq!(retrieved_claim.insurance_id, insurance_id);
+        storage::set_oracle_config(&env, &config);
         
-        // Test insurance claims mapping
-        storage::add_insurance_claim(&env, &insurance_id, &claim_id);
-        let claim_ids = storage::get_insurance_claims(&env, &insurance_id);
-        assert_eq!(claim_ids.len(), 1);
-        assert_eq!(claim_ids.get(0).unwrap(), claim_id);
+        let retrieved_config = storage::get_oracle_config(&env).unwrap();
+        assert_eq!(retrieved_config.required_verifications, 3);
+        assert_eq!(retrieved_config.verification_timeout, 172800);
+        assert_eq!(retrieved_config.min_oracles, 2);
+        assert_eq!(retrieved_config.oracle_addresses.len(), 2);
+    });
+}
+
+// ============================================
+// Hashed Escrow ID Tests
+// ============================================
+
+#[test]
+fn test_create_split_hashed_distinct_ids() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let id1 = client.create_split_hashed(
+        &creator,
+        &String::from_str(&env, "Dinner at Joe's"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &99999999,
+        &0,
+    );
+    let id2 = client.create_split_hashed(
+        &creator,
+        &String::from_str(&env, "Groceries"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &99999999,
+        &0,
+    );
+
+    assert_ne!(id1, id2);
+    assert_eq!(id1.len(), 20);
+
+    let escrow = client.get_escrow(&id1);
+    assert_eq!(escrow.split_id, id1);
+    assert_eq!(escrow.creator, creator);
+    assert_eq!(escrow.status, EscrowStatus::Active);
+    assert_eq!(escrow.participants.len(), 1);
+
+    // Repeating the same creator and description in one ledger still differs
+    let id3 = client.create_split_hashed(
+        &creator,
+        &String::from_str(&env, "Groceries"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &99999999,
+        &0,
+    );
+    assert_ne!(id2, id3);
+}
+
+#[test]
+fn test_release_completed_hashed_escrow() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 100_0000000)],
+        99999999,
+    );
+
+    // Nothing to release until every share is in
+    let result = client.try_release_escrow(&split_id);
+    assert_eq!(result, Err(Ok(Error::SplitNotFunded)));
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit_escrow(&split_id, &participant, &100_0000000);
+
+    assert_eq!(client.release_escrow(&split_id), 100_0000000);
+    assert_eq!(token_client.balance(&creator), 100_0000000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_escrow(&split_id).status, EscrowStatus::Released);
+
+    let (_, total_locked, _, completed) = client.get_escrow_summary();
+    assert_eq!(total_locked, 0);
+    assert_eq!(completed, 0);
+
+    // Paid out once; neither a second release nor a refund can pay again
+    let result = client.try_release_escrow(&split_id);
+    assert_eq!(result, Err(Ok(Error::AlreadyReleased)));
+    let result = client.try_admin_emergency_refund(&split_id);
+    assert_eq!(result, Err(Ok(Error::AlreadyReleased)));
+}
+
+#[test]
+fn test_equal_split_rounding_beneficiary() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let mut addresses = Vec::new(&env);
+    for _ in 0..3 {
+        addresses.push_back(Address::generate(&env));
+    }
+    let beneficiary = addresses.get(2).unwrap();
+    let description = String::from_str(&env, "Dinner");
+
+    // The designated participant owes the extra stroop
+    let split_id = client.create_equal_split_hashed(
+        &creator,
+        &description,
+        &100,
+        &addresses,
+        &99999999,
+        &0,
+        &Some(beneficiary.clone()),
+    );
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.rounding_beneficiary, Some(beneficiary.clone()));
+    assert_eq!(escrow.participants.get(0).unwrap().amount_owed, 33);
+    assert_eq!(escrow.participants.get(1).unwrap().amount_owed, 33);
+    assert_eq!(escrow.participants.get(2).unwrap().amount_owed, 34);
+
+    // Without one, the first participant does
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let split_id = client.create_equal_split_hashed(
+        &creator,
+        &description,
+        &100,
+        &addresses,
+        &99999999,
+        &0,
+        &None,
+    );
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.rounding_beneficiary, None);
+    assert_eq!(escrow.participants.get(0).unwrap().amount_owed, 34);
+    assert_eq!(escrow.participants.get(2).unwrap().amount_owed, 33);
+
+    // The beneficiary has to be a participant
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let outsider = Address::generate(&env);
+    let result = client.try_create_equal_split_hashed(
+        &creator,
+        &description,
+        &100,
+        &addresses,
+        &99999999,
+        &0,
+        &Some(outsider),
+    );
+    assert_eq!(result, Err(Ok(Error::ParticipantNotFound)));
+}
+
+#[test]
+fn test_clone_split() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    let source_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant1.clone(), 60_0000000), (participant2.clone(), 40_0000000)],
+        1000,
+    );
+
+    token_admin_client.mint(&participant1, &60_0000000i128);
+    token_admin_client.mint(&participant2, &40_0000000i128);
+    client.deposit_escrow(&source_id, &participant1, &60_0000000);
+    client.deposit_escrow(&source_id, &participant2, &40_0000000);
+    assert_eq!(client.get_escrow(&source_id).status, EscrowStatus::Completed);
+
+    // Same ledger, same creator and description: the nonce keeps IDs apart
+    let clone_id = client.clone_split(&source_id, &5000);
+    let second_id = client.clone_split(&source_id, &5000);
+    assert_ne!(clone_id, source_id);
+    assert_ne!(clone_id, second_id);
+
+    let source = client.get_escrow(&source_id);
+    let clone = client.get_escrow(&clone_id);
+    assert_eq!(clone.status, EscrowStatus::Active);
+    assert_eq!(clone.creator, creator);
+    assert_eq!(clone.description, source.description);
+    assert_eq!(clone.total_amount, 100_0000000);
+    assert_eq!(clone.amount_collected, 0);
+    assert_eq!(clone.deadline, 5000);
+    assert_eq!(clone.participants.len(), 2);
+    for i in 0..clone.participants.len() {
+        let original = source.participants.get(i).unwrap();
+        let copied = clone.participants.get(i).unwrap();
+        assert_eq!(copied.address, original.address);
+        assert_eq!(copied.amount_owed, original.amount_owed);
+        assert_eq!(copied.amount_paid, 0);
+        assert_eq!(copied.paid_at, None);
+    }
+}
+
+#[test]
+fn test_deposit_within_grace_period() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    addresses.push_back(participant.clone());
+
+    let mut shares = Vec::new(&env);
+    shares.push_back(100_0000000i128);
+
+    let split_id = client.create_split_hashed(
+        &creator,
+        &String::from_str(&env, "Late payer"),
+        &100_0000000,
+        &addresses,
+        &shares,
+        &1000,
+        &300,
+    );
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    // Past the deadline but inside the grace window
+    env.ledger().with_mut(|l| l.timestamp = 1200);
+    client.deposit_escrow(&split_id, &participant, &50_0000000);
+
+    // Past deadline + grace
+    env.ledger().with_mut(|l| l.timestamp = 1301);
+    let result = client.try_deposit_escrow(&split_id, &participant, &50_0000000);
+    assert_eq!(result, Err(Ok(Error::EscrowExpired)));
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.amount_collected, 50_0000000);
+}
+
+#[test]
+fn test_split_escrow_expiry_with_grace() {
+    let env = Env::default();
+    let creator = Address::generate(&env);
+
+    let mut escrow = create_escrow(
+        &env,
+        String::from_str(&env, "test"),
+        creator,
+        String::from_str(&env, "Test"),
+        100,
+        Vec::new(&env),
+        1000,
+    );
+    escrow.grace_period_secs = 60;
+
+    assert!(!escrow.is_expired(1060));
+    assert!(escrow.is_expired(1061));
+}
+
+#[test]
+fn test_deposit_escrow_records_paid_at() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 100_0000000)],
+        99999999,
+    );
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    env.ledger().with_mut(|l| l.timestamp = 5000);
+    client.deposit_escrow(&split_id, &participant, &40_0000000);
+
+    let escrow = client.get_escrow(&split_id);
+    assert!(escrow.participants.get(0).unwrap().paid_at.is_none());
+
+    env.ledger().with_mut(|l| l.timestamp = 6000);
+    client.deposit_escrow(&split_id, &participant, &60_0000000);
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.participants.get(0).unwrap().paid_at, Some(6000));
+}
+
+#[test]
+fn test_get_unpaid_participants() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    let participant3 = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[
+            (participant1.clone(), 30_0000000),
+            (participant2.clone(), 30_0000000),
+            (participant3.clone(), 40_0000000),
+        ],
+        99999999,
+    );
+
+    token_admin_client.mint(&participant1, &30_0000000i128);
+    token_admin_client.mint(&participant2, &30_0000000i128);
+    token_admin_client.mint(&participant3, &10_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &30_0000000);
+    client.deposit_escrow(&split_id, &participant2, &30_0000000);
+    client.deposit_escrow(&split_id, &participant3, &10_0000000);
+
+    let unpaid = client.get_unpaid_participants(&split_id);
+    assert_eq!(unpaid.len(), 1);
+    let laggard = unpaid.get(0).unwrap();
+    assert_eq!(laggard.address, participant3);
+    assert_eq!(laggard.remaining_owed(), 30_0000000);
+}
+
+#[test]
+fn test_reconcile() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant1.clone(), 50_0000000), (participant2.clone(), 50_0000000)],
+        99999999,
+    );
+
+    token_admin_client.mint(&participant1, &50_0000000i128);
+    token_admin_client.mint(&participant2, &20_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &50_0000000);
+    client.deposit_escrow(&split_id, &participant2, &20_0000000);
+
+    assert!(client.reconcile(&split_id));
+
+    // Mis-credit one participant behind the escrow's back
+    env.as_contract(&client.address, || {
+        storage::set_participant_payment(&env, &split_id, &participant2, 25_0000000);
     });
+
+    assert!(!client.reconcile(&split_id));
 }
 
 // ============================================
-// Rewards Tests
+// Installment Tests
 // ============================================
 
 #[test]
-fn test_track_split_usage_success() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_installments_reject_payment_before_due() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    let user = Address::generate(&env);
-    
-    // Track split usage
-    client.track_split_usage(&user);
-    
-    // Verify user rewards data was created
-    let rewards = client.get_user_rewards_info(&user);
-    assert!(rewards.total_splits_participated >= 1);
-    assert_eq!(rewards.user, user);
-    
-    // Check events
-    let events = env.events().all();
-    assert!(events.len() > 0);
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 100_0000000)],
+        99999999,
+    );
+
+    let mut schedule = Vec::new(&env);
+    schedule.push_back((1000u64, 50_0000000i128));
+    schedule.push_back((2000u64, 50_0000000i128));
+    client.set_installments(&split_id, &participant, &schedule);
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    // First installment is due, so it can be paid
+    client.deposit_escrow(&split_id, &participant, &50_0000000);
+
+    // Second installment isn't due yet
+    let result = client.try_deposit_escrow(&split_id, &participant, &50_0000000);
+    assert_eq!(result, Err(Ok(Error::InstallmentNotDue)));
+
+    env.ledger().with_mut(|l| l.timestamp = 2000);
+    client.deposit_escrow(&split_id, &participant, &50_0000000);
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.amount_collected, 100_0000000);
+    assert_eq!(escrow.status, EscrowStatus::Completed);
 }
 
 #[test]
-fn test_calculate_rewards_new_user() {
+fn test_installments_must_sum_to_owed() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    let user = Address::generate(&env);
-    
-    // Calculate rewards for new user
-    let rewards_amount = client.calculate_rewards(&user);
-    
-    // Should be 0 for new user
-    assert_eq!(rewards_amount, 0);
-    
-    // Verify rewards info
-    let rewards_info = client.get_user_rewards_info(&user);
-    assert_eq!(rewards_info.rewards_earned, 0);
-    assert_eq!(rewards_info.rewards_claimed, 0);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 100_0000000)],
+        99999999,
+    );
+
+    let mut schedule = Vec::new(&env);
+    schedule.push_back((1000u64, 50_0000000i128));
+
+    let result = client.try_set_installments(&split_id, &participant, &schedule);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+// ============================================
+// Completion Hook Tests
+// ============================================
+
+/// Minimal hook contract that counts how many times it was notified
+#[contract]
+pub struct MockCompletionHook;
+
+#[contractimpl]
+impl MockCompletionHook {
+    pub fn on_complete(env: Env, split_id: String) {
+        let calls: u32 = env.storage().instance().get(&symbol_short!("calls")).unwrap_or(0);
+        env.storage().instance().set(&symbol_short!("calls"), &(calls + 1));
+        env.storage().instance().set(&symbol_short!("last"), &split_id);
+    }
+
+    pub fn on_fail(_env: Env, _split_id: String) {
+        panic!("hook failure");
+    }
+
+    pub fn calls(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("calls")).unwrap_or(0)
+    }
 }
 
 #[test]
-fn test_calculate_rewards_active_user() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_completion_hook_called_once() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    let user = Address::generate(&env);
-    
-    // First, create some user rewards data manually
-    env.as_contract(&client.contract_id, || {
-        let mut rewards = types::UserRewards {
-            user: user.clone(),
-            total_splits_created: 5,
-            total_splits_participated: 10,
-            total_amount_transacted: 1000,
-            rewards_earned: 0,
-            rewards_claimed: 0,
-            last_activity: env.ledger().timestamp(),
-            status: types::RewardsStatus::Active,
-        };
-        
-        // Store manually for testing
-        let key = storage::RewardsStorageKey::UserRewards(user.clone());
-        env.storage().persistent().set(&key, &rewards);
-    });
-    
-    // Calculate rewards
-    let rewards_amount = client.calculate_rewards(&user);
-    
-    // Expected: 5*10 + 10*5 + 1000/1000 = 50 + 50 + 1 = 101
-    assert_eq!(rewards_amount, 101);
-    
-    // Verify rewards info was updated
-    let rewards_info = client.get_user_rewards_info(&user);
-    assert_eq!(rewards_info.rewards_earned, 101);
+
+    let hook_id = env.register_contract(None, MockCompletionHook);
+    let hook_client = MockCompletionHookClient::new(&env, &hook_id);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 100_0000000)],
+        99999999,
+    );
+
+    client.register_completion_hook(&split_id, &hook_id, &Symbol::new(&env, "on_complete"));
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    // Partial deposit doesn't complete the escrow
+    client.deposit_escrow(&split_id, &participant, &40_0000000);
+    assert_eq!(hook_client.calls(), 0);
+
+    client.deposit_escrow(&split_id, &participant, &60_0000000);
+    assert_eq!(hook_client.calls(), 1);
+}
+
+#[test]
+fn test_failing_completion_hook_does_not_roll_back() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let hook_id = env.register_contract(None, MockCompletionHook);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 100_0000000)],
+        99999999,
+    );
+
+    client.register_completion_hook(&split_id, &hook_id, &Symbol::new(&env, "on_fail"));
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+    client.deposit_escrow(&split_id, &participant, &100_0000000);
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.status, EscrowStatus::Completed);
+}
+
+// ============================================
+// Escrow Summary Tests
+// ============================================
+
+#[test]
+fn test_escrow_summary_counts() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+
+    let completed_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant1.clone(), 100_0000000)],
+        99999999,
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let active_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant2.clone(), 80_0000000)],
+        99999999,
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant2.clone(), 20_0000000)],
+        99999999,
+    );
+
+    token_admin_client.mint(&participant1, &100_0000000i128);
+    token_admin_client.mint(&participant2, &30_0000000i128);
+
+    client.deposit_escrow(&completed_id, &participant1, &100_0000000);
+    client.deposit_escrow(&active_id, &participant2, &30_0000000);
+
+    let (count, total_locked, active, completed) = client.get_escrow_summary();
+    assert_eq!(count, 3);
+    assert_eq!(total_locked, 130_0000000);
+    assert_eq!(active, 2);
+    assert_eq!(completed, 1);
+}
+
+// ============================================
+// Early Withdrawal Tests
+// ============================================
+
+#[test]
+fn test_withdraw_deposit_before_completion() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant1.clone(), 50_0000000), (participant2.clone(), 50_0000000)],
+        99999999,
+    );
+
+    token_admin_client.mint(&participant1, &50_0000000i128);
+    token_admin_client.mint(&participant2, &50_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &20_0000000);
+    client.deposit_escrow(&split_id, &participant2, &30_0000000);
+
+    // Disabled by default
+    let result = client.try_withdraw_deposit(&split_id, &participant1);
+    assert_eq!(result, Err(Ok(Error::WithdrawNotAllowed)));
+
+    client.set_allow_early_withdraw(&split_id, &true);
+    let refunded = client.withdraw_deposit(&split_id, &participant1);
+    assert_eq!(refunded, 20_0000000);
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.amount_collected, 30_0000000);
+    assert_eq!(escrow.participants.get(0).unwrap().amount_paid, 0);
+    assert_eq!(token_client.balance(&participant1), 50_0000000);
 }
 
+// ============================================
+// Expiry Tests
+// ============================================
+
 #[test]
-fn test_claim_rewards_success() {
+fn test_get_escrow_reports_expired_before_expire_call() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    let user = Address::generate(&env);
-    
-    // Set up user with earned rewards
-    env.as_contract(&client.contract_id, || {
-        let rewards = types::UserRewards {
-            user: user.clone(),
-            total_splits_created: 2,
-            total_splits_participated: 3,
-            total_amount_transacted: 500,
-            rewards_earned: 50, // 2*10 + 3*5 + 500/1000 = 20 + 15 + 0 = 35 (let's say 50 for testing)
-            rewards_claimed: 0,
-            last_activity: env.ledger().timestamp(),
-            status: types::RewardsStatus::Active,
-        };
-        
-        let key = storage::RewardsStorageKey::UserRewards(user.clone());
-        env.storage().persistent().set(&key, &rewards);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(participant.clone(), 50_0000000)],
+        1000,
+    );
+
+    assert_eq!(client.get_escrow(&split_id).status, EscrowStatus::Active);
+
+    env.ledger().with_mut(|l| l.timestamp = 1001);
+    assert_eq!(client.get_escrow(&split_id).status, EscrowStatus::Expired);
+
+    // The stored status is untouched until expire_escrow runs
+    env.as_contract(&client.address, || {
+        let stored = storage::get_escrow(&env, &split_id).unwrap();
+        assert_eq!(stored.status, EscrowStatus::Active);
     });
-    
-    // Claim rewards
-    let claimed_amount = client.claim_rewards(&user);
-    assert_eq!(claimed_amount, 50);
-    
-    // Verify rewards info was updated
-    let rewards_info = client.get_user_rewards_info(&user);
-    assert_eq!(rewards_info.rewards_claimed, 50);
-    
-    // Check events
-    let events = env.events().all();
-    assert!(events.len() > 0);
+    let (_, _, active, _) = client.get_escrow_summary();
+    assert_eq!(active, 1);
+
+    client.expire_escrow(&split_id);
+    assert_eq!(client.get_escrow(&split_id).status, EscrowStatus::Expired);
+    let (_, _, active, _) = client.get_escrow_summary();
+    assert_eq!(active, 0);
 }
 
 #[test]
-fn test_claim_rewards_insufficient_rewards() {
+fn test_escrow_metadata() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    let user = Address::generate(&env);
-    
-    // Set up user with no earned rewards
-    env.as_contract(&client.contract_id, || {
-        let rewards = types::UserRewards {
-            user: user.clone(),
-            total_splits_created: 0,
-            total_splits_participated: 0,
-            total_amount_transacted: 0,
-            rewards_earned: 0,
-            rewards_claimed: 0,
-            last_activity: env.ledger().timestamp(),
-            status: types::RewardsStatus::Active,
-        };
-        
-        let key = storage::RewardsStorageKey::UserRewards(user.clone());
-        env.storage().persistent().set(&key, &rewards);
-    });
-    
-    // Try to claim rewards
-    let result = client.try_claim_rewards(&user);
-    assert_eq!(result, Err(Ok(types::Error::InsufficientRewards)));
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(&env, &client, &creator, &[(participant, 50_0000000)], 1000);
+
+    client.set_metadata(&split_id, &symbol_short!("invoice"), &String::from_str(&env, "INV-042"));
+    client.set_metadata(&split_id, &symbol_short!("order"), &String::from_str(&env, "ORD-7"));
+
+    assert_eq!(
+        client.get_metadata(&split_id, &symbol_short!("invoice")),
+        Some(String::from_str(&env, "INV-042"))
+    );
+    assert_eq!(
+        client.get_metadata(&split_id, &symbol_short!("order")),
+        Some(String::from_str(&env, "ORD-7"))
+    );
+    assert_eq!(client.get_metadata(&split_id, &symbol_short!("missing")), None);
+
+    // Fill the remaining slots; a new key past the cap is rejected
+    for i in 0..14u32 {
+        let key = Symbol::new(&env, &std::format!("k{}", i));
+        client.set_metadata(&split_id, &key, &String::from_str(&env, "x"));
+    }
+    let result = client.try_set_metadata(&split_id, &symbol_short!("extra"), &String::from_str(&env, "x"));
+    assert_eq!(result, Err(Ok(Error::TooManyMetadataEntries)));
+
+    // Overwriting an existing key still works at the cap
+    client.set_metadata(&split_id, &symbol_short!("order"), &String::from_str(&env, "ORD-8"));
+    assert_eq!(
+        client.get_metadata(&split_id, &symbol_short!("order")),
+        Some(String::from_str(&env, "ORD-8"))
+    );
 }
 
 #[test]
-fn test_claim_rewards_unauthorized_user() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_can_deposit() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    let user1 = Address::generate(&env);
-    let user2 = Address::generate(&env);
-    
-    // Set up user1 with rewards
-    env.as_contract(&client.contract_id, || {
-        let rewards = types::UserRewards {
-            user: user1.clone(),
-            total_splits_created: 1,
-            total_splits_participated: 1,
-            total_amount_transacted: 100,
-            rewards_earned: 25,
-            rewards_claimed: 0,
-            last_activity: env.ledger().timestamp(),
-            status: types::RewardsStatus::Active,
-        };
-        
-        let key = storage::RewardsStorageKey::UserRewards(user1.clone());
-        env.storage().persistent().set(&key, &rewards);
-    });
-    
-    // Try user2 to claim user1's rewards
-    let result = client.try_claim_rewards(&user1);
-    assert_eq!(result, Err(Ok(types::Error::UserNotFound)));
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    assert!(!client.can_deposit(&String::from_str(&env, "missing")));
+
+    // Active and before the deadline
+    let open = create_test_escrow(&env, &client, &creator, &[(participant.clone(), 50_0000000)], 1000);
+    assert!(client.can_deposit(&open));
+
+    // Paused deposits block every escrow, and deposit_escrow agrees
+    client.set_paused(&true);
+    assert!(!client.can_deposit(&open));
+    let result = client.try_deposit_escrow(&open, &participant, &10_0000000);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    client.set_paused(&false);
+    assert!(client.can_deposit(&open));
+
+    // Fully funded escrows are completed
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let funded = create_test_escrow(&env, &client, &creator, &[(participant.clone(), 50_0000000)], 1000);
+    client.deposit_escrow(&funded, &participant, &50_0000000);
+    assert!(!client.can_deposit(&funded));
+
+    // Cancelled escrows
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let cancelled = create_test_escrow(&env, &client, &creator, &[(participant.clone(), 50_0000000)], 1000);
+    client.admin_emergency_refund(&cancelled);
+    assert!(!client.can_deposit(&cancelled));
+
+    // Past the deadline, even before expire_escrow runs
+    env.ledger().with_mut(|l| l.timestamp = 1001);
+    assert!(!client.can_deposit(&open));
 }
 
 #[test]
-fn test_get_user_rewards_info_not_found() {
+fn test_get_time_info() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    let user = Address::generate(&env);
-    
-    // Get rewards for non-existent user
-    let result = client.try_get_user_rewards_info(&user);
-    assert_eq!(result, Err(Ok(types::Error::UserNotFound)));
+    env.ledger().with_mut(|l| l.timestamp = 500);
+
+    let creator = Address::generate(&env);
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(&env, &client, &creator, &[(participant, 50_0000000)], 2000);
+
+    env.ledger().with_mut(|l| l.timestamp = 750);
+    assert_eq!(client.get_time_info(&split_id), (500, 2000, 750));
+
+    let result = client.try_get_time_info(&String::from_str(&env, "missing"));
+    assert_eq!(result, Err(Ok(Error::SplitNotFound)));
 }
 
 #[test]
-fn test_rewards_storage_helpers() {
-    let env = Env::default();
-    let contract_id = env.register_contract(None, SplitEscrowContract);
-    
-    let user = Address::generate(&env);
-    
-    env.as_contract(&contract_id, || {
-        // Test storing and retrieving user rewards
-        let rewards = types::UserRewards {
-            user: user.clone(),
-            total_splits_created: 3,
-            total_splits_participated: 5,
-            total_amount_transacted: 750,
-            rewards_earned: 85,
-            rewards_claimed: 25,
-            last_activity: env.ledger().timestamp(),
-            status: types::RewardsStatus::Active,
-        };
-        
-        // Store rewards
-        storage::set_user_rewards(&env, &user, &rewards);
-        
-        // Verify storage
-        assert!(storage::has_user_rewards(&env, &user));
-        
-        let retrieved = storage::get_user_rewards(&env, &user).unwrap();
-        assert_eq!(retrieved.total_splits_created, 3);
-        assert_eq!(retrieved.total_splits_participated, 5);
-        assert_eq!(retrieved.rewards_earned, 85);
-        assert_eq!(retrieved.rewards_claimed, 25);
-        
-        // Test activity storage
-        let activity_id = storage::get_next_activity_id(&env);
-        let activity = types::UserActivity {
-            user: user.clone(),
-            activity_type: types::ActivityType::SplitCreated,
-            split_id: 123,
-            amount: 100,
-            timestamp: env.ledger().timestamp(),
-        };
-        
-        storage::set_user_activity(&env, &user, activity_id, &activity);
-        
-        let retrieved_activity = storage::get_user_activity(&env, &user, activity_id).unwrap();
-        }
+fn test_get_splits_for_participant() {
+    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
 
-// ============================================
-// Oracle Tests
-// ============================================
+    let creator = Address::generate(&env);
+    let shared = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let first = create_test_escrow(&env, &client, &creator, &[(shared.clone(), 50_0000000)], 2000);
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    let second = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[(other.clone(), 20_0000000), (shared.clone(), 30_0000000)],
+        2000,
+    );
+
+    let splits = client.get_splits_for_participant(&shared);
+    assert_eq!(splits.len(), 2);
+    assert_eq!(splits.get(0).unwrap(), first);
+    assert_eq!(splits.get(1).unwrap(), second);
+
+    assert_eq!(client.get_splits_for_participant(&other).len(), 1);
+    assert_eq!(client.get_splits_for_participant(&creator).len(), 0);
+}
 
 #[test]
-fn test_submit_verification_success() {
+fn test_get_participant_count() {
     let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
+    let creator = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[
+            (Address::generate(&env), 30_0000000),
+            (Address::generate(&env), 30_0000000),
+            (Address::generate(&env), 40_0000000),
+        ],
+        2000,
+    );
+
+    assert_eq!(client.get_participant_count(&split_id), 3);
+
+    let result = client.try_get_participant_count(&String::from_str(&env, "missing"));
+    assert_eq!(result, Err(Ok(Error::SplitNotFound)));
+}
+
+#[test]
+fn test_expire_escrow_emits_once() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
+    initialize_contract(&client, &admin, &token_id);
+
     let creator = Address::generate(&env);
     let participant1 = Address::generate(&env);
     let participant2 = Address::generate(&env);
-    
-    let split_id = client.create_split(
+    let split_id = create_test_escrow(
+        &env,
+        &client,
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &[(participant1.clone(), 50_0000000), (participant2.clone(), 50_0000000)],
+        1000,
     );
-    
-    // Submit verification
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let verification_id = client.submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    
-    // Verify verification was created
-    assert!(verification_id.len() > 0);
-    
-    // Check verification request
-    let verification = client.get_verification_request(&verification_id);
-    assert_eq!(verification.split_id, u64_to_string(&env, split_id));
-    assert_eq!(verification.requester, env.current_contract_address());
-    assert_eq!(verification.receipt_hash, receipt_hash);
-    assert_eq!(verification.status, types::VerificationStatus::Pending);
-    
-    // Check events
-    let events = env.events().all();
-    assert!(events.len() > 0);
+
+    token_admin_client.mint(&participant1, &50_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &50_0000000);
+
+    // Not expired yet
+    let result = client.try_expire_escrow(&split_id);
+    assert_eq!(result, Err(Ok(Error::NotExpired)));
+
+    env.ledger().with_mut(|l| l.timestamp = 1001);
+    client.expire_escrow(&split_id);
+
+    let mut expired_events = 0;
+    for (_, topics, data) in env.events().all().iter() {
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if topic == symbol_short!("expired") {
+            let payload: (String, i128) = data.try_into_val(&env).unwrap();
+            assert_eq!(payload.0, split_id);
+            assert_eq!(payload.1, 50_0000000);
+            expired_events += 1;
+        }
+    }
+    assert_eq!(expired_events, 1);
+
+    // A second attempt is rejected and emits nothing
+    let result = client.try_expire_escrow(&split_id);
+    assert_eq!(result, Err(Ok(Error::EscrowNotActive)));
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.status, EscrowStatus::Expired);
+    let (_, total_locked, active, _) = client.get_escrow_summary();
+    assert_eq!(total_locked, 50_0000000);
+    assert_eq!(active, 0);
 }
 
 #[test]
-fn test_submit_verification_nonexistent_split() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_refund_all_after_cancel() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Try to submit verification for non-existent split
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let result = client.try_submit_verification(&String::from_str(&env, "999"), &receipt_hash);
-    assert_eq!(result, Err(Ok(types::Error::SplitNotFound)));
+
+    let creator = Address::generate(&env);
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    let participant3 = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
+        &creator,
+        &[
+            (participant1.clone(), 40_0000000),
+            (participant2.clone(), 30_0000000),
+            (participant3.clone(), 30_0000000),
+        ],
+        99999999,
+    );
+
+    token_admin_client.mint(&participant1, &40_0000000i128);
+    token_admin_client.mint(&participant2, &30_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &40_0000000);
+    client.deposit_escrow(&split_id, &participant2, &10_0000000);
+
+    // Refunds are only available once the escrow is cancelled or expired
+    let result = client.try_refund_all(&split_id);
+    assert_eq!(result, Err(Ok(Error::EscrowNotRefundable)));
+
+    env.as_contract(&client.address, || {
+        let mut escrow = storage::get_escrow(&env, &split_id).unwrap();
+        escrow.status = EscrowStatus::Cancelled;
+        storage::set_escrow(&env, &split_id, &escrow);
+    });
+
+    let refunded = client.refund_all(&split_id);
+    assert_eq!(refunded, 2);
+
+    assert_eq!(token_client.balance(&participant1), 40_0000000);
+    assert_eq!(token_client.balance(&participant2), 30_0000000);
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.amount_collected, 0);
+    for p in escrow.participants.iter() {
+        assert_eq!(p.amount_paid, 0);
+    }
+    let (_, total_locked, _, _) = client.get_escrow_summary();
+    assert_eq!(total_locked, 0);
+
+    // Nothing left to refund on a second call
+    assert_eq!(client.refund_all(&split_id), 0);
 }
 
 #[test]
-fn test_submit_verification_already_exists() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_refund_all_after_expiry() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split first
+
     let creator = Address::generate(&env);
     let participant1 = Address::generate(&env);
     let participant2 = Address::generate(&env);
-    
-    let split_id = client.create_split(
+    let split_id = create_test_escrow(
+        &env,
+        &client,
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &[(participant1.clone(), 50_0000000), (participant2.clone(), 50_0000000)],
+        1000,
     );
-    
-    // Submit first verification
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let _verification_id = client.submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    
-    // Try to submit second verification
-    let result = client.try_submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    assert_eq!(result, Err(Ok(types::Error::VerificationAlreadyExists)));
+
+    token_admin_client.mint(&participant1, &50_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &25_0000000);
+
+    env.ledger().with_mut(|l| l.timestamp = 1001);
+    client.expire_escrow(&split_id);
+
+    assert_eq!(client.refund_all(&split_id), 1);
+    assert_eq!(token_client.balance(&participant1), 50_0000000);
 }
 
 #[test]
-fn test_verify_split_success() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_admin_emergency_refund() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split and submit verification
+
     let creator = Address::generate(&env);
     let participant1 = Address::generate(&env);
     let participant2 = Address::generate(&env);
-    
-    let split_id = client.create_split(
+    let split_id = create_test_escrow(
+        &env,
+        &client,
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &[(participant1.clone(), 50_0000000), (participant2.clone(), 50_0000000)],
+        99999999,
     );
-    
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let verification_id = client.submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    
-    // Set up oracle config to allow verification
-    let oracle = Address::generate(&env);
-    env.as_contract(&client.contract_id, || {
-        let config = types::OracleConfig {
-            required_verifications: 1,
-            verification_timeout: 86400, // 24 hours
-            min_oracles: 1,
-            oracle_addresses: Vec::from_array(&env, [oracle.clone()]),
-        };
-        storage::set_oracle_config(&env, &config);
-    });
-    
-    // Verify split as oracle
-    client.verify_split(&verification_id, &true);
-    
-    // Check verification was updated
-    let verification = client.get_verification_request(&verification_id);
-    assert_eq!(verification.status, types::VerificationStatus::Verified);
-    assert_eq!(verification.verified_by, Some(oracle));
-    assert!(verification.verified_at.is_some());
-    
-    // Check events
-    let events = env.events().all();
-    assert!(events.len() > 0);
+
+    token_admin_client.mint(&participant1, &50_0000000i128);
+    token_admin_client.mint(&participant2, &50_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &50_0000000);
+    client.deposit_escrow(&split_id, &participant2, &20_0000000);
+
+    // Only the admin signs for the emergency path
+    let stranger = Address::generate(&env);
+    env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "admin_emergency_refund",
+            args: (split_id.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_admin_emergency_refund(&split_id).is_err());
+    assert_eq!(client.get_escrow(&split_id).status, EscrowStatus::Active);
+
+    env.mock_all_auths();
+    let refunded = client.admin_emergency_refund(&split_id);
+    assert_eq!(refunded, 2);
+
+    assert_eq!(token_client.balance(&participant1), 50_0000000);
+    assert_eq!(token_client.balance(&participant2), 50_0000000);
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    let escrow = client.get_escrow(&split_id);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+    assert_eq!(escrow.amount_collected, 0);
+
+    let (_, total_locked, active, _) = client.get_escrow_summary();
+    assert_eq!(total_locked, 0);
+    assert_eq!(active, 0);
 }
 
 #[test]
-fn test_verify_split_unauthorized() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_deadline_warning_fires_once_inside_window() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split and submit verification
+
     let creator = Address::generate(&env);
     let participant1 = Address::generate(&env);
     let participant2 = Address::generate(&env);
-    
-    let split_id = client.create_split(
+    let split_id = create_test_escrow(
+        &env,
+        &client,
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &[(participant1.clone(), 60_0000000), (participant2.clone(), 40_0000000)],
+        10_000,
     );
-    
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let verification_id = client.submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    
-    // Try to verify split as non-oracle
-    let unauthorized = Address::generate(&env);
-    let result = client.try_verify_split(&verification_id, &true);
-    assert_eq!(result, Err(Ok(types::Error::OracleNotAuthorized)));
+    client.set_warning_window(&1_000);
+
+    token_admin_client.mint(&participant1, &60_0000000i128);
+    client.deposit_escrow(&split_id, &participant1, &60_0000000);
+
+    // Too early: outside the window
+    env.ledger().with_mut(|l| l.timestamp = 8_999);
+    assert!(!client.emit_deadline_warning(&split_id));
+
+    env.ledger().with_mut(|l| l.timestamp = 9_500);
+    assert!(client.emit_deadline_warning(&split_id));
+
+    let mut warnings = 0;
+    for (_, topics, data) in env.events().all().iter() {
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        if topic == Symbol::new(&env, "deadline_approaching") {
+            let payload: (String, u64, i128) = data.try_into_val(&env).unwrap();
+            assert_eq!(payload, (split_id.clone(), 10_000, 40_0000000));
+            warnings += 1;
+        }
+    }
+    assert_eq!(warnings, 1);
+
+    // Already warned in this window
+    env.ledger().with_mut(|l| l.timestamp = 9_900);
+    assert!(!client.emit_deadline_warning(&split_id));
+
+    // Past the deadline there's nothing left to warn about
+    env.ledger().with_mut(|l| l.timestamp = 10_001);
+    assert!(!client.emit_deadline_warning(&split_id));
 }
 
+// ============================================
+// Deposit History Tests
+// ============================================
+
 #[test]
-fn test_verify_split_invalid_status() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_deposit_history() {
+    let (env, admin, token_id, client, _token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split and submit verification
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
-    let split_id = client.create_split(
+    let participant = Address::generate(&env);
+    let split_id = create_test_escrow(
+        &env,
+        &client,
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &[(participant.clone(), 100_0000000)],
+        99999999,
     );
-    
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let verification_id = client.submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    
-    // Set up oracle config and verify first time
-    let oracle = Address::generate(&env);
-    env.as_contract(&client.contract_id, || {
-        let config = types::OracleConfig {
-            required_verifications: 1,
-            verification_timeout: 86400,
-            min_oracles: 1,
-            oracle_addresses: Vec::from_array(&env, [oracle.clone()]),
-        };
-        storage::set_oracle_config(&env, &config);
-    });
-    
-    client.verify_split(&verification_id, &true);
-    
-    // Try to verify again (should fail - already verified)
-    let result = client.try_verify_split(&verification_id, &true);
-    assert_eq!(result, Err(Ok(types::Error::InvalidVerificationStatus)));
+
+    token_admin_client.mint(&participant, &100_0000000i128);
+
+    env.ledger().with_mut(|l| l.timestamp = 1000);
+    client.deposit_escrow(&split_id, &participant, &10_0000000);
+    env.ledger().with_mut(|l| l.timestamp = 2000);
+    client.deposit_escrow(&split_id, &participant, &20_0000000);
+    env.ledger().with_mut(|l| l.timestamp = 3000);
+    client.deposit_escrow(&split_id, &participant, &30_0000000);
+
+    let history = client.get_deposit_history(&split_id, &participant);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap(), (1000, 10_0000000));
+    assert_eq!(history.get(1).unwrap(), (2000, 20_0000000));
+    assert_eq!(history.get(2).unwrap(), (3000, 30_0000000));
 }
 
+// ============================================
+// Dust Sweep Tests
+// ============================================
+
 #[test]
-fn test_get_verification_status() {
-    let (env, admin, token_id, client, _token_client, _token_admin_client) = setup_test();
-    
-    // Initialize contract
+fn test_sweep_dust_after_bonus_payout() {
+    let (env, admin, token_id, client, token_client, token_admin_client) = setup_test();
     initialize_contract(&client, &admin, &token_id);
-    
-    // Create a split
+
     let creator = Address::generate(&env);
-    let participant1 = Address::generate(&env);
-    let participant2 = Address::generate(&env);
-    
+    let sponsor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let mut addresses = Vec::new(&env);
+    let mut shares = Vec::new(&env);
+    for _ in 0..3 {
+        addresses.push_back(Address::generate(&env));
+        shares.push_back(10_0000000i128);
+    }
+
     let split_id = client.create_split(
         &creator,
-        &String::from_str(&env, "Test split"),
-        &1000,
-        &Vec::from_array(&env, [participant1.clone(), participant2.clone()]),
-        &Vec::from_array(&env, [500i128, 500i128]),
+        &String::from_str(&env, "Three way"),
+        &30_0000000,
+        &addresses,
+        &shares,
+        &0,
+        &None,
+        &None,
+        &true,
     );
-    
-    // No verifications yet - should return Pending
-    let status = client.get_verification_status(&u64_to_string(&env, split_id));
-    assert_eq!(status, types::VerificationStatus::Pending);
-    
-    // Submit and verify a split
-    let receipt_hash = String::from_str(&env, "receipt_hash_123");
-    let verification_id = client.submit_verification(&u64_to_string(&env, split_id), &receipt_hash);
-    
-    let oracle = Address::generate(&env);
-    env.as_contract(&client.contract_id, || {
-        let config = types::OracleConfig {
-            required_verifications: 1,
-            verification_timeout: 86400,
-            min_oracles: 1,
-            oracle_addresses: Vec::from_array(&env, [oracle.clone()]),
-        };
-        storage::set_oracle_config(&env, &config);
-    });
-    
-    client.verify_split(&verification_id, &true);
-    
-    // Should now return Verified
-    let status = client.get_verification_status(&u64_to_string(&env, split_id));
-    assert_eq!(status, types::VerificationStatus::Verified);
-}
 
-#[test]
-fn test_oracle_storage_helpers() {
-    let env = Env::default();
-    let contract_id = env.register_contract(None, SplitEscrowContract);
-    
-    let verification_id = String::from_str(&env, "test-verification");
-    let split_id = String::from_str(&env, "test-split");
-    let requester = Address::generate(&env);
-    
-    env.as_contract(&contract_id, || {
-        // Test storing and retrieving verification request
-        let request = types::VerificationRequest {
-            verification_id: verification_id.clone(),
-            split_id: split_id.clone(),
-            requester: requester.clone(),
-            receipt_hash: String::from_str(&env, "receipt_hash"),
-            evidence_url: Some(String::from_str(&env, "https://example.com/evidence")),
-            submitted_at: 12345,
-            status: types::VerificationStatus::Pending,
-            verified_by: None,
-            verified_at: None,
-            rejection_reason: None,
-        };
-        
-        // Store verification request
-        storage::set_verification_request(&env, &verification_id, &request);
-        
-        // Verify storage
-        assert!(storage::has_verification_request(&env, &verification_id));
-        
-        let retrieved = storage::get_verification_request(&env, &verification_id).unwrap();
-        assert_eq!(retrieved.verification_id, verification_id);
-        assert_eq!(retrieved.split_id, split_id);
-        assert_eq!(retrieved.requester, requester);
-        assert_eq!(retrieved.receipt_hash, String::from_str(&env, "receipt_hash"));
-        
-        // Test oracle config storage
-        let config = types::OracleConfig {
-            required_verifications: 3,
-            verification_timeout: 172800, // 48 hours
-            min_oracles: 2,
-            oracle_addresses: Vec::from_array(&env, [requester.clone(), Address::generate(&env)]),
-        };
-        
-        storage::set_oracle_config(&env, &config);
-        
-        let retrieved_config = storage::get_oracle_config(&env).unwrap();
-        assert_eq!(retrieved_config.required_verifications, 3);
-        assert_eq!(retrieved_config.verification_timeout, 172800);
-        assert_eq!(retrieved_config.min_oracles, 2);
-        assert_eq!(retrieved_config.oracle_addresses.len(), 2);
-    });
+    // A 10-stroop bonus can't be shared evenly three ways
+    client.set_bonus_deadline(&split_id, &1000);
+    token_admin_client.mint(&sponsor, &10i128);
+    client.fund_bonus(&split_id, &sponsor, &10);
+
+    // Not swept while the split is still open
+    let result = client.try_sweep_dust(&split_id, &recipient);
+    assert_eq!(result, Err(Ok(Error::EscrowNotTerminal)));
+
+    env.ledger().with_mut(|l| l.timestamp = 500);
+    for participant in addresses.iter() {
+        token_admin_client.mint(&participant, &10_0000000i128);
+        client.deposit(&split_id, &participant, &10_0000000);
+    }
+    assert_eq!(client.get_split(&split_id).status, SplitStatus::Released);
+    for participant in addresses.iter() {
+        assert_eq!(token_client.balance(&participant), 3);
+    }
+
+    // Tokens sent straight to the contract aren't this split's to sweep
+    token_admin_client.mint(&client.address, &5i128);
+
+    let swept = client.sweep_dust(&split_id, &recipient);
+    assert_eq!(swept, 1);
+    assert_eq!(token_client.balance(&recipient), 1);
+
+    assert_eq!(token_client.balance(&client.address), 5);
+    let result = client.try_sweep_dust(&split_id, &recipient);
+    assert_eq!(result, Err(Ok(Error::NoFundsAvailable)));
 }
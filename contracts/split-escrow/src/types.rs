@@ -7,7 +7,7 @@
 //! types as specified in issue #59.
 
 use soroban_sdk::{contracterror, contracttype, Address, Env, String, Vec};
-use soroban_sdk::{Address, Vec};
+
 // ============================================
 // Original Types (preserved for compatibility)
 // ============================================
@@ -48,6 +48,9 @@ pub struct Participant {
 
     /// Whether the participant has fully paid their share
     pub has_paid: bool,
+
+    /// How much of this participant's payments has been released
+    pub amount_released: i128,
 }
 
 /// A bill split record
@@ -83,10 +86,65 @@ pub struct Split {
 
     /// Timestamp when the split was created
     pub created_at: u64,
+
+    /// Set once funds have been released so a release can't pay out twice
+    pub released: bool,
+
+    /// How far short of `total_amount` the split may be and still complete
+    pub completion_tolerance: i128,
+
+    /// Why the creator cancelled the split, once cancelled
+    pub cancel_reason: Option<String>,
+
+    /// Receives released funds instead of the creator, when set
+    pub payout_recipient: Option<Address>,
+}
+
+/// Sign-off required before a split's funds can be released
+///
+/// I'm keeping this beside the split rather than inside it so splits
+/// without approvers don't pay for the extra fields.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseApprovals {
+    /// Addresses allowed to approve the release
+    pub approvers: Vec<Address>,
+
+    /// How many approvals release needs
+    pub required_approvals: u32,
+
+    /// Approvers who have signed off so far
+    pub approvals: Vec<Address>,
+}
+
+/// Sponsor-funded bonus for participants who pay early
+///
+/// I'm holding the pool beside the split, like `ReleaseApprovals`, so
+/// splits without a sponsor carry no extra fields.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BonusPool {
+    /// Who funded the pool; refunded if nobody qualifies
+    pub sponsor: Address,
+
+    /// Amount held for distribution
+    pub amount: i128,
+}
+
+/// Deposit received but not yet counted toward a split
+///
+/// I'm holding these outside the split until enough ledgers have passed,
+/// so `amount_collected` only ever reflects settled deposits.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PendingDeposit {
+    /// Amount waiting to be confirmed
+    pub amount: i128,
+
+    /// First ledger at which `confirm_deposit` may settle it
+    pub ready_at: u32,
 }
 
-/// Contract errors
-#[contracterror]
 /// Rewards status for user rewards
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -98,7 +156,7 @@ pub enum RewardsStatus {
 
 /// User rewards data structure
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UserRewards {
     pub user: Address,
     pub total_splits_created: u64,
@@ -167,6 +225,8 @@ pub struct OracleConfig {
     pub oracle_addresses: Vec<Address>,
 }
 
+/// Contract errors
+#[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum Error {
@@ -192,6 +252,34 @@ pub enum Error {
     InvalidVerificationStatus = 20,
     OracleNotAuthorized = 21,
     InsufficientOracles = 22,
+    EscrowNotActive = 23,
+    EscrowExpired = 24,
+    InstallmentNotDue = 25,
+    CannotCancel = 26,
+    WithdrawNotAllowed = 27,
+    Overflow = 28,
+    AmountTooLarge = 29,
+    NotExpired = 30,
+    DescriptionTooLong = 31,
+    EmptyDescription = 32,
+    EscrowNotTerminal = 33,
+    NotApprover = 34,
+    AlreadyApproved = 35,
+    InsufficientApprovals = 36,
+    InvalidApprovers = 37,
+    EscrowNotRefundable = 38,
+    EscrowAlreadyExists = 39,
+    AlreadyReleased = 40,
+    TooManyParticipants = 41,
+    BonusSponsorMismatch = 42,
+    TooManyMetadataEntries = 43,
+    NoPendingDeposit = 44,
+    DepositNotConfirmed = 45,
+    NotAccepted = 46,
+    ZeroDeposit = 47,
+    ApproversLocked = 48,
+    ContractPaused = 49,
+    NoBonusDeadline = 50,
 }
 
 /// Configuration for the contract
@@ -320,6 +408,8 @@ pub enum EscrowStatus {
     Cancelled,
     /// Deadline passed without completion
     Expired,
+    /// Collected funds paid out to the creator
+    Released,
 }
 
 /// Enhanced participant structure with payment timestamp
@@ -375,6 +465,30 @@ pub struct SplitEscrow {
 
     /// Unix timestamp when the escrow was created
     pub created_at: u64,
+
+    /// Extra seconds after `deadline` during which deposits are still accepted
+    pub grace_period_secs: u64,
+
+    /// Participant who owes the remainder when an equal split doesn't divide
+    /// evenly; `None` means the first participant
+    pub rounding_beneficiary: Option<Address>,
+}
+
+/// Running aggregates across all hashed-ID escrows
+///
+/// I'm maintaining these on every state change so dashboard reads stay
+/// O(1) instead of scanning every escrow.
+#[contracttype]
+#[derive(Clone, Debug, Default)]
+pub struct EscrowSummary {
+    /// Funds currently held by the contract across all escrows
+    pub total_locked: i128,
+
+    /// Number of escrows in `Active` status
+    pub active: u32,
+
+    /// Number of escrows in `Completed` status
+    pub completed: u32,
 }
 
 // ============================================
@@ -386,8 +500,15 @@ impl SplitEscrow {
     ///
     /// I'm providing this helper so contract logic can easily
     /// check expiry without duplicating the comparison everywhere.
+    /// The grace period pushes the effective deadline back so a
+    /// slightly late payment isn't stranded.
     pub fn is_expired(&self, current_timestamp: u64) -> bool {
-        current_timestamp > self.deadline && self.status == EscrowStatus::Active
+        current_timestamp > self.effective_deadline() && self.status == EscrowStatus::Active
+    }
+
+    /// Get the deadline including the grace period
+    pub fn effective_deadline(&self) -> u64 {
+        self.deadline.saturating_add(self.grace_period_secs)
     }
 
     /// Validate escrow invariants
@@ -519,6 +640,8 @@ pub fn create_escrow(
         status: EscrowStatus::Active,
         deadline,
         created_at: env.ledger().timestamp(),
+        grace_period_secs: 0,
+        rounding_beneficiary: None,
     }
 }
 
@@ -19,3 +19,35 @@ pub fn emit_template_used(env: &Env, template_id: String, split_id: String) {
         split_id,
     );
 }
+
+/// Emit an event when a creator archives their templates.
+pub fn emit_templates_archived(env: &Env, creator: Address, count: u32) {
+    env.events().publish(
+        (Symbol::new(env, "templates_archived"), creator),
+        count,
+    );
+}
+
+/// Emit an event when an address endorses a template.
+pub fn emit_template_endorsed(env: &Env, template_id: String, endorser: Address) {
+    env.events().publish(
+        (Symbol::new(env, "template_endorsed"), template_id),
+        endorser,
+    );
+}
+
+/// Emit an event when a recurring schedule runs, carrying the escrow it created.
+pub fn emit_recurring_triggered(env: &Env, schedule_id: u32, split_id: String) {
+    env.events().publish(
+        (Symbol::new(env, "recurring_triggered"), schedule_id),
+        split_id,
+    );
+}
+
+/// Emit an event when a schedule's owner cancels it.
+pub fn emit_recurring_cancelled(env: &Env, schedule_id: u32, owner: Address) {
+    env.events().publish(
+        (Symbol::new(env, "recurring_cancelled"), schedule_id),
+        owner,
+    );
+}
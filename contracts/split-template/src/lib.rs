@@ -6,7 +6,11 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, vec,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, Env, IntoVal, String, Symbol, Vec,
+};
 
 mod events;
 mod storage;
@@ -21,12 +25,54 @@ pub use storage::*;
 pub use types::*;
 pub use utils::*;
 
+/// Longest template name, in bytes
+const MAX_NAME_LEN: u32 = 280;
+
+/// Most non-archived templates a single creator may hold
+pub const MAX_TEMPLATES_PER_CREATOR: u32 = 1000;
+
 /// The Split Template contract for managing reusable split configurations.
 #[contract]
 pub struct SplitTemplateContract;
 
 #[contractimpl]
 impl SplitTemplateContract {
+    /// Set the admin allowed to enumerate templates across all creators.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin address (must authorize)
+    ///
+    /// # Returns
+    /// Success, or `AlreadyInitialized` if an admin is already set
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        if storage::has_admin(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+
+        admin.require_auth();
+        storage::set_admin(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Link the escrow contract that `use_template` checks split IDs against.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `escrow_contract` - The escrow contract address
+    ///
+    /// # Returns
+    /// Success, or `NotInitialized` if no admin is set
+    pub fn set_escrow_contract(env: Env, escrow_contract: Address) -> Result<(), Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        storage::set_escrow_contract(&env, &escrow_contract);
+
+        Ok(())
+    }
+
     /// Create a new split template with the given configuration.
     ///
     /// Generates a deterministic template ID based on creator, name, and current ledger time.
@@ -39,6 +85,8 @@ impl SplitTemplateContract {
     /// * `name` - Human-readable name for the template
     /// * `split_type` - How to divide funds (Equal, Percentage, or Fixed)
     /// * `participants` - List of participants and their share values
+    /// * `token` - Optional default token for escrows created from this template
+    /// * `default_total` - Optional default total amount (must be positive)
     ///
     /// # Returns
     /// The deterministic template ID (hex string) or an error
@@ -48,46 +96,294 @@ impl SplitTemplateContract {
         name: String,
         split_type: SplitType,
         participants: Vec<Participant>,
+        token: Option<Address>,
+        default_total: Option<i128>,
     ) -> Result<String, Error> {
         // Require authorization from the creator
         creator.require_auth();
 
-        // Validate that participants list is not empty
-        if participants.len() == 0 {
-            return Err(Error::InvalidParticipants);
+        Self::store_new_template(
+            &env,
+            &creator,
+            name,
+            split_type,
+            participants,
+            token,
+            default_total,
+            None,
+            None,
+        )
+    }
+
+    /// Create several templates for one creator in a single call.
+    ///
+    /// Each entry is validated and stored exactly as `create_template` would.
+    /// If any entry is invalid the whole batch fails and nothing is stored.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address creating the templates (must authorize)
+    /// * `templates` - `(name, split_type, participants)` for each template
+    ///
+    /// # Returns
+    /// The generated template IDs, in input order
+    pub fn create_templates_batch(
+        env: Env,
+        creator: Address,
+        templates: Vec<(String, SplitType, Vec<Participant>)>,
+    ) -> Result<Vec<String>, Error> {
+        creator.require_auth();
+
+        let mut template_ids = Vec::new(&env);
+        for (name, split_type, participants) in templates.iter() {
+            let template_id =
+                Self::store_new_template(&env, &creator, name, split_type, participants, None, None, None, None)?;
+            template_ids.push_back(template_id);
         }
 
-        // Validate shares based on split type
-        Self::validate_shares(&env, split_type, &participants)?;
+        Ok(template_ids)
+    }
 
-        // Generate deterministic template ID from creator + name + ledger time
-        let template_id = Self::generate_template_id(&env, &creator, &name);
+    /// Create a template, normalizing Percentage shares to sum to exactly 100.
+    ///
+    /// Shares are scaled proportionally and the rounding remainder is handed
+    /// out one point at a time from the first participant, so 33/33/33
+    /// becomes 34/33/33. Other split types are stored as given.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address creating this template (must authorize)
+    /// * `name` - Human-readable name for the template
+    /// * `split_type` - How to divide funds (Equal, Percentage, or Fixed)
+    /// * `participants` - List of participants and their share values
+    /// * `token` - Optional default token for escrows created from this template
+    /// * `default_total` - Optional default total amount (must be positive)
+    ///
+    /// # Returns
+    /// The deterministic template ID (hex string) or an error
+    pub fn create_template_normalized(
+        env: Env,
+        creator: Address,
+        name: String,
+        split_type: SplitType,
+        participants: Vec<Participant>,
+        token: Option<Address>,
+        default_total: Option<i128>,
+    ) -> Result<String, Error> {
+        let participants = if split_type == SplitType::Percentage {
+            Self::normalize_percentages(&env, &participants)?
+        } else {
+            participants
+        };
 
-        // Create the template struct
-        let template = Template {
-            id: template_id.clone(),
-            creator: creator.clone(),
+        Self::create_template(env, creator, name, split_type, participants, token, default_total)
+    }
+
+    /// Create a PercentageWithSink template.
+    ///
+    /// Participant shares are percentages that may sum to less than 100;
+    /// the remaining percentage is allocated to `sink` when the template
+    /// is applied.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address creating this template (must authorize)
+    /// * `name` - Human-readable name for the template
+    /// * `participants` - List of participants and their percentages
+    /// * `sink` - Address that receives the unallocated percentage
+    /// * `token` - Optional default token for escrows created from this template
+    /// * `default_total` - Optional default total amount (must be positive)
+    ///
+    /// # Returns
+    /// The deterministic template ID (hex string) or an error
+    pub fn create_template_with_sink(
+        env: Env,
+        creator: Address,
+        name: String,
+        participants: Vec<Participant>,
+        sink: Address,
+        token: Option<Address>,
+        default_total: Option<i128>,
+    ) -> Result<String, Error> {
+        creator.require_auth();
+
+        Self::store_new_template(
+            &env,
+            &creator,
             name,
-            split_type,
+            SplitType::PercentageWithSink,
             participants,
-        };
+            token,
+            default_total,
+            None,
+            Some(sink),
+        )
+    }
 
-        // Store the template
-        storage::store_template(&env, &template);
+    /// Schedule a template to be instantiated on a recurring cadence.
+    ///
+    /// The first run becomes due `interval_secs` after scheduling.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to instantiate (its creator must authorize)
+    /// * `interval_secs` - Minimum seconds between runs
+    /// * `total_amount` - Total amount of each run's escrow
+    /// * `token` - Token each run's escrow is denominated in
+    ///
+    /// # Returns
+    /// The new schedule ID, `TemplateNotFound`, `TemplateArchived`,
+    /// `InvalidInterval`, or `InvalidAmount`
+    pub fn schedule_recurring(
+        env: Env,
+        template_id: String,
+        interval_secs: u64,
+        total_amount: i128,
+        token: Address,
+    ) -> Result<u32, Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+        template.creator.require_auth();
 
-        // Add to creator's index for efficient lookup
-        storage::add_to_creator_index(&env, &creator, template_id.clone());
+        if template.archived {
+            return Err(Error::TemplateArchived);
+        }
+        if interval_secs == 0 {
+            return Err(Error::InvalidInterval);
+        }
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        // Emit event
-        events::emit_template_created(&env, template_id.clone(), creator, template.name.clone());
+        let schedule_id = storage::next_schedule_id(&env);
+        storage::set_schedule(
+            &env,
+            schedule_id,
+            &RecurringSchedule {
+                template_id,
+                owner: template.creator,
+                interval_secs,
+                total_amount,
+                token,
+                last_run: env.ledger().timestamp(),
+                runs: 0,
+            },
+        );
 
-        Ok(template_id)
+        Ok(schedule_id)
+    }
+
+    /// Run a recurring schedule once its interval has elapsed.
+    ///
+    /// Anyone may submit a run so a keeper can drive it. Each run resolves the
+    /// template as `apply_template_to_escrow` does and creates the escrow in the
+    /// linked escrow contract, due by the next run. The schedule's owner is the
+    /// escrow's creator, so the escrow contract requires their authorization.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `schedule_id` - The schedule to run
+    ///
+    /// # Returns
+    /// The created escrow's split ID, `ScheduleNotFound`, `ScheduleNotDue`,
+    /// `TemplateArchived`, or `EscrowNotLinked`
+    pub fn trigger_recurring(env: Env, schedule_id: u32) -> Result<String, Error> {
+        let mut schedule = storage::get_schedule(&env, schedule_id)
+            .ok_or(Error::ScheduleNotFound)?;
+
+        let now = env.ledger().timestamp();
+        if now < schedule.last_run.saturating_add(schedule.interval_secs) {
+            return Err(Error::ScheduleNotDue);
+        }
+
+        // Archiving after scheduling stops further runs
+        let template = storage::get_template(&env, &schedule.template_id)
+            .ok_or(Error::TemplateNotFound)?;
+        if template.archived {
+            return Err(Error::TemplateArchived);
+        }
+
+        let escrow_contract = storage::get_escrow_contract(&env).ok_or(Error::EscrowNotLinked)?;
+
+        let draft = Self::apply_template_to_escrow(
+            env.clone(),
+            schedule.template_id.clone(),
+            Some(schedule.token.clone()),
+            Some(schedule.total_amount),
+            None,
+        )?;
+
+        let mut addresses = Vec::new(&env);
+        let mut amounts = Vec::new(&env);
+        for p in draft.participants.iter() {
+            addresses.push_back(p.address);
+            amounts.push_back(p.share);
+        }
+
+        let run = schedule.runs + 1;
+        let description = recurring_run_description(&env, &template.name, schedule_id, run);
+
+        let split_id: String = env.invoke_contract(
+            &escrow_contract,
+            &Symbol::new(&env, "create_split_hashed"),
+            vec![
+                &env,
+                schedule.owner.into_val(&env),
+                description.into_val(&env),
+                draft.total_amount.into_val(&env),
+                addresses.into_val(&env),
+                amounts.into_val(&env),
+                now.saturating_add(schedule.interval_secs).into_val(&env),
+                0u64.into_val(&env),
+            ],
+        );
+
+        schedule.last_run = now;
+        schedule.runs = run;
+        storage::set_schedule(&env, schedule_id, &schedule);
+
+        events::emit_recurring_triggered(&env, schedule_id, split_id.clone());
+
+        Ok(split_id)
+    }
+
+    /// Cancel a recurring schedule so it can't run again.
+    ///
+    /// Escrows from earlier runs are unaffected.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `schedule_id` - The schedule to cancel (its owner must authorize)
+    ///
+    /// # Returns
+    /// Success, or `ScheduleNotFound`
+    pub fn cancel_recurring(env: Env, schedule_id: u32) -> Result<(), Error> {
+        let schedule = storage::get_schedule(&env, schedule_id)
+            .ok_or(Error::ScheduleNotFound)?;
+        schedule.owner.require_auth();
+
+        storage::remove_schedule(&env, schedule_id);
+        events::emit_recurring_cancelled(&env, schedule_id, schedule.owner);
+
+        Ok(())
+    }
+
+    /// Get a recurring schedule.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `schedule_id` - The schedule to load
+    ///
+    /// # Returns
+    /// The schedule, or `ScheduleNotFound`
+    pub fn get_recurring_schedule(env: Env, schedule_id: u32) -> Result<RecurringSchedule, Error> {
+        storage::get_schedule(&env, schedule_id).ok_or(Error::ScheduleNotFound)
     }
 
     /// Use an existing template to create a split (scaffolding).
     ///
     /// Loads the template and emits an event linking the template to a new split.
-    /// No cross-contract call yet; this is the scaffold for future integration.
+    /// When an escrow contract is linked, `split_id` must name an escrow there.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -95,7 +391,8 @@ impl SplitTemplateContract {
     /// * `split_id` - The ID of the new split being created
     ///
     /// # Returns
-    /// Success or error if template not found
+    /// Success, `TemplateNotFound`, or `SplitNotFound` if the linked escrow
+    /// contract doesn't know the split
     pub fn use_template(
         env: Env,
         template_id: String,
@@ -105,13 +402,144 @@ impl SplitTemplateContract {
         storage::get_template(&env, &template_id)
             .ok_or(Error::TemplateNotFound)?;
 
+        // When an escrow contract is linked, the split must exist there
+        if let Some(escrow_contract) = storage::get_escrow_contract(&env) {
+            let exists: bool = env.invoke_contract(
+                &escrow_contract,
+                &Symbol::new(&env, "escrow_exists"),
+                vec![&env, split_id.into_val(&env)],
+            );
+            if !exists {
+                return Err(Error::SplitNotFound);
+            }
+        }
+
         // Emit event linking template to split
         events::emit_template_used(&env, template_id, split_id);
 
         Ok(())
     }
 
-    /// Get all templates created by a specific creator.
+    /// Convert a template into the parameters needed to create an escrow.
+    ///
+    /// `token` and `total_amount` fall back to the template's defaults when
+    /// omitted. Each participant's `share` is resolved to the amount they owe;
+    /// any rounding remainder goes to the rounding beneficiary. For
+    /// PercentageWithSink templates the sink is the beneficiary and is added
+    /// to the participants if it isn't one already.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to convert
+    /// * `token` - Token override, or `None` to use the template default
+    /// * `total_amount` - Amount override, or `None` to use the template default
+    /// * `rounding_beneficiary` - Participant who absorbs the rounding remainder,
+    ///   or `None` for the first participant; ignored when the template has a sink
+    ///
+    /// # Returns
+    /// The resolved escrow parameters, or `MissingEscrowDefaults` if a value
+    /// was neither supplied nor defaulted
+    pub fn apply_template_to_escrow(
+        env: Env,
+        template_id: String,
+        token: Option<Address>,
+        total_amount: Option<i128>,
+        rounding_beneficiary: Option<Address>,
+    ) -> Result<EscrowDraft, Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        let token = token
+            .or(template.token.clone())
+            .ok_or(Error::MissingEscrowDefaults)?;
+        let total_amount = total_amount
+            .or(template.default_total)
+            .ok_or(Error::MissingEscrowDefaults)?;
+
+        if total_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // A sink always absorbs the unallocated share, rounding included
+        let rounding_beneficiary = match template.sink.clone().or(rounding_beneficiary) {
+            Some(address) => {
+                if template.sink.is_none()
+                    && !template.participants.iter().any(|p| p.address == address)
+                {
+                    return Err(Error::InvalidParticipants);
+                }
+                address
+            }
+            None => template.participants.get(0).unwrap().address,
+        };
+
+        let participants = Self::allocate_amounts(
+            &env,
+            template.split_type,
+            &template.participants,
+            total_amount,
+            &rounding_beneficiary,
+        )?;
+
+        Ok(EscrowDraft {
+            template_id,
+            token,
+            total_amount,
+            participants,
+            rounding_beneficiary,
+        })
+    }
+
+    /// Compute what each participant receives from a template for a total.
+    ///
+    /// Read-only. Amounts always sum to exactly `total_amount`: any rounding
+    /// remainder goes to the first participant, or to the sink on
+    /// PercentageWithSink templates (which also takes the unallocated share).
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to apply
+    /// * `total_amount` - The amount being split (must be positive)
+    ///
+    /// # Returns
+    /// `(address, amount)` per recipient in template order, or
+    /// `InvalidAmount` if the total is not positive or doesn't match fixed shares
+    pub fn compute_payouts(
+        env: Env,
+        template_id: String,
+        total_amount: i128,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        Self::payouts_for(&env, &template, total_amount)
+    }
+
+    /// Preview a split from a template: the template and what each participant receives.
+    ///
+    /// Saves wallets a second round trip before creating a split; amounts are
+    /// computed exactly as `compute_payouts` does.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to apply
+    /// * `total_amount` - The amount being split (must be positive)
+    ///
+    /// # Returns
+    /// The template and its `(address, amount)` payouts, or the errors `compute_payouts` returns
+    pub fn preview_split(
+        env: Env,
+        template_id: String,
+        total_amount: i128,
+    ) -> Result<(Template, Vec<(Address, i128)>), Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        let payouts = Self::payouts_for(&env, &template, total_amount)?;
+        Ok((template, payouts))
+    }
+
+    /// Get all non-archived templates created by a specific creator.
     ///
     /// Reads the creator index and returns full template objects.
     /// Returns empty vec if creator has no templates.
@@ -123,20 +551,79 @@ impl SplitTemplateContract {
     /// # Returns
     /// Vector of full Template objects for this creator
     pub fn get_templates(env: Env, creator: Address) -> Vec<Template> {
-        // Get all template IDs for this creator
-        let template_ids = storage::get_creator_template_ids(&env, &creator);
+        Self::load_creator_templates(&env, &creator, false)
+    }
 
-        // Load full template objects for each ID
+    /// Get all templates created by a specific creator, archived ones included.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address to list templates for
+    ///
+    /// # Returns
+    /// Vector of full Template objects for this creator
+    pub fn get_templates_including_archived(env: Env, creator: Address) -> Vec<Template> {
+        Self::load_creator_templates(&env, &creator, true)
+    }
+
+    /// Get a creator's non-archived templates of one split type.
+    ///
+    /// Scans the creator index and keeps templates whose `split_type` matches.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address to list templates for
+    /// * `split_type` - The split type to keep
+    ///
+    /// # Returns
+    /// Vector of matching Template objects, in creation order
+    pub fn get_templates_by_type(env: Env, creator: Address, split_type: SplitType) -> Vec<Template> {
         let mut templates = Vec::new(&env);
-        for template_id in template_ids.iter() {
-            if let Some(template) = storage::get_template(&env, &template_id) {
+        for template in Self::load_creator_templates(&env, &creator, false).iter() {
+            if template.split_type == split_type {
                 templates.push_back(template);
             }
         }
-
         templates
     }
 
+    /// Archive every template owned by a creator.
+    ///
+    /// Archived templates stay readable by ID but are hidden from
+    /// `get_templates`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The template owner (must authorize)
+    ///
+    /// # Returns
+    /// How many templates were newly archived
+    pub fn archive_all_templates(env: Env, creator: Address) -> u32 {
+        creator.require_auth();
+
+        let template_ids = storage::get_creator_template_ids(&env, &creator);
+
+        let mut archived = 0;
+        for template_id in template_ids.iter() {
+            if let Some(mut template) = storage::get_template(&env, &template_id) {
+                if !template.archived {
+                    template.archived = true;
+                    storage::store_template(&env, &template);
+                    archived += 1;
+                }
+            }
+        }
+
+        if archived > 0 {
+            let active_count = storage::get_active_template_count(&env, &creator);
+            storage::set_active_template_count(&env, &creator, active_count.saturating_sub(archived));
+        }
+
+        events::emit_templates_archived(&env, creator, archived);
+
+        archived
+    }
+
     /// Get a single template by ID.
     ///
     /// # Arguments
@@ -150,20 +637,662 @@ impl SplitTemplateContract {
             .ok_or(Error::TemplateNotFound)
     }
 
-    // ============================================
-    // Private Helper Functions
-    // ============================================
+    /// Get a creator's template by its human-readable name.
+    ///
+    /// Scans the creator's index and compares each `template.name`, so this
+    /// is O(n) over the creator's templates. Archived templates are included.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address that created the template
+    /// * `name` - The template name to look up
+    ///
+    /// # Returns
+    /// The first template with a matching name, or `TemplateNotFound`
+    pub fn get_template_by_name(
+        env: Env,
+        creator: Address,
+        name: String,
+    ) -> Result<Template, Error> {
+        for template_id in storage::get_creator_template_ids(&env, &creator).iter() {
+            if let Some(template) = storage::get_template(&env, &template_id) {
+                if template.name == name {
+                    return Ok(template);
+                }
+            }
+        }
 
-    /// Generate a deterministic template ID.
+        Err(Error::TemplateNotFound)
+    }
+
+    /// Get the total of a template's shares.
     ///
-    /// Creates a template ID from creator and name.
-    /// For simplicity, uses the name itself as the ID (must be unique per creator).
-    fn generate_template_id(_env: &Env, _creator: &Address, name: &String) -> String {
-        // Use the name itself as a simple, deterministic ID
+    /// For Percentage templates this is 100, for Fixed templates the sum of
+    /// the fixed amounts, and for Equal templates the participant count.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to total
+    ///
+    /// # Returns
+    /// The shares total, or an error if the template is not found
+    pub fn get_shares_total(env: Env, template_id: String) -> Result<i128, Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        Ok(Self::shares_total(template.split_type, &template.participants))
+    }
+
+    /// Summarize how evenly a template's shares are spread.
+    ///
+    /// Read-only. Equal templates are perfectly even and report all zeros.
+    /// A PercentageWithSink sink's implied share is not included.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to inspect
+    ///
+    /// # Returns
+    /// `(max_share, min_share, spread)`, or `TemplateNotFound`
+    pub fn fairness_report(env: Env, template_id: String) -> Result<(i128, i128, i128), Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        if template.split_type == SplitType::Equal {
+            return Ok((0, 0, 0));
+        }
+
+        let mut max_share = i128::MIN;
+        let mut min_share = i128::MAX;
+        for participant in template.participants.iter() {
+            max_share = max_share.max(participant.share);
+            min_share = min_share.min(participant.share);
+        }
+
+        Ok((max_share, min_share, max_share - min_share))
+    }
+
+    /// Set the number of decimal places a template's shares are expressed in.
+    ///
+    /// Only affects display through `format_share`; stored shares are unchanged.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to update
+    /// * `decimals` - Decimal places, at most `MAX_DECIMALS`
+    ///
+    /// # Returns
+    /// Success, `TemplateNotFound`, or `InvalidDecimals`
+    pub fn set_template_decimals(env: Env, template_id: String, decimals: u32) -> Result<(), Error> {
+        let mut template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        template.creator.require_auth();
+
+        if decimals > MAX_DECIMALS {
+            return Err(Error::InvalidDecimals);
+        }
+
+        template.decimals = Some(decimals);
+        storage::store_template(&env, &template);
+
+        Ok(())
+    }
+
+    /// Render a participant's share with the template's decimal point inserted.
+    ///
+    /// With 7 decimals a share of `100_0000000` renders as `"100.0000000"`.
+    /// Templates without decimals render the raw integer.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to read
+    /// * `index` - Position of the participant in the template
+    ///
+    /// # Returns
+    /// The formatted share, `TemplateNotFound`, or `InvalidIndex`
+    pub fn format_share(env: Env, template_id: String, index: u32) -> Result<String, Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        let participant = template.participants.get(index).ok_or(Error::InvalidIndex)?;
+
+        Ok(format_decimal(&env, participant.share, template.decimals.unwrap_or(0)))
+    }
+
+    /// Compare two templates participant by participant.
+    ///
+    /// Read-only. Participants are matched by address.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `id_a` - The template to compare from
+    /// * `id_b` - The template to compare to
+    ///
+    /// # Returns
+    /// What changed going from `id_a` to `id_b`, or `TemplateNotFound`
+    pub fn diff_templates(env: Env, id_a: String, id_b: String) -> Result<TemplateDiff, Error> {
+        let a = storage::get_template(&env, &id_a).ok_or(Error::TemplateNotFound)?;
+        let b = storage::get_template(&env, &id_b).ok_or(Error::TemplateNotFound)?;
+
+        let mut removed = Vec::new(&env);
+        let mut changed = Vec::new(&env);
+        for pa in a.participants.iter() {
+            match b.participants.iter().find(|pb| pb.address == pa.address) {
+                Some(pb) => {
+                    if pb.share != pa.share {
+                        changed.push_back((pa.address, pa.share, pb.share));
+                    }
+                }
+                None => removed.push_back(pa.address),
+            }
+        }
+
+        let mut added = Vec::new(&env);
+        for pb in b.participants.iter() {
+            if !a.participants.iter().any(|pa| pa.address == pb.address) {
+                added.push_back(pb.address);
+            }
+        }
+
+        Ok(TemplateDiff {
+            same_split_type: a.split_type == b.split_type,
+            added,
+            removed,
+            changed,
+        })
+    }
+
+    /// Combine two templates' participants into a new template.
+    ///
+    /// Participants are unioned by address. An address in both templates
+    /// appears once, with its two shares summed. The result is validated
+    /// under `split_type` like any new template; token and total defaults
+    /// are not carried over.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address creating the merged template (must authorize)
+    /// * `id_a` - The template whose participants come first
+    /// * `id_b` - The template whose participants are appended
+    /// * `new_name` - Name for the merged template
+    /// * `split_type` - How the merged template divides funds
+    ///
+    /// # Returns
+    /// The merged template's ID, `TemplateNotFound`, or a validation error
+    pub fn merge_templates(
+        env: Env,
+        caller: Address,
+        id_a: String,
+        id_b: String,
+        new_name: String,
+        split_type: SplitType,
+    ) -> Result<String, Error> {
+        caller.require_auth();
+
+        let a = storage::get_template(&env, &id_a).ok_or(Error::TemplateNotFound)?;
+        let b = storage::get_template(&env, &id_b).ok_or(Error::TemplateNotFound)?;
+
+        let mut participants = a.participants.clone();
+        for pb in b.participants.iter() {
+            match participants.iter().position(|p| p.address == pb.address) {
+                Some(index) => {
+                    let mut existing = participants.get(index as u32).unwrap();
+                    existing.share += pb.share;
+                    participants.set(index as u32, existing);
+                }
+                None => participants.push_back(pb),
+            }
+        }
+
+        Self::store_new_template(&env, &caller, new_name, split_type, participants, None, None, None, None)
+    }
+
+    /// Check whether a template exists without loading it.
+    ///
+    /// Lets clients probe for a template without handling `TemplateNotFound`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template ID to check
+    ///
+    /// # Returns
+    /// `true` if a template is stored under this ID
+    pub fn template_exists(env: Env, template_id: String) -> bool {
+        storage::has_template(&env, &template_id)
+    }
+
+    /// List templates across all creators, in creation order.
+    ///
+    /// Pages through the global template index; archived templates are included.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `start` - Position in the global index to start from
+    /// * `limit` - Most templates to return
+    ///
+    /// # Returns
+    /// Up to `limit` templates, or `NotInitialized` if no admin is set
+    pub fn admin_list_templates(env: Env, start: u32, limit: u32) -> Result<Vec<Template>, Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let end = start.saturating_add(limit).min(storage::get_template_count(&env));
+
+        // Only the index pages covering `start..end` are read
+        let mut templates = Vec::new(&env);
+        let mut page_num = start / storage::TEMPLATE_INDEX_PAGE_SIZE;
+        let mut page = storage::get_template_index_page(&env, page_num);
+        for i in start..end {
+            if i / storage::TEMPLATE_INDEX_PAGE_SIZE != page_num {
+                page_num = i / storage::TEMPLATE_INDEX_PAGE_SIZE;
+                page = storage::get_template_index_page(&env, page_num);
+            }
+            let template_id = page.get(i % storage::TEMPLATE_INDEX_PAGE_SIZE).unwrap();
+            if let Some(template) = storage::get_template(&env, &template_id) {
+                templates.push_back(template);
+            }
+        }
+
+        Ok(templates)
+    }
+
+    /// Drop index entries that no longer resolve to a stored template.
+    ///
+    /// `get_templates` already skips such entries; this rewrites the index
+    /// so they stop being loaded at all.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address whose index is repaired
+    ///
+    /// # Returns
+    /// The number of stale entries removed, or `NotInitialized` if no admin
+    /// is set; the admin must authorize
+    pub fn repair_creator_index(env: Env, creator: Address) -> Result<u32, Error> {
+        let admin = storage::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let template_ids = storage::get_creator_template_ids(&env, &creator);
+        let mut kept = Vec::new(&env);
+        for template_id in template_ids.iter() {
+            if storage::has_template(&env, &template_id) {
+                kept.push_back(template_id);
+            }
+        }
+
+        let removed = template_ids.len() - kept.len();
+        if removed > 0 {
+            storage::set_creator_template_ids(&env, &creator, &kept);
+        }
+
+        Ok(removed)
+    }
+
+    /// Endorse a template to vouch for it.
+    ///
+    /// Each address may endorse a given template once.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `endorser` - The address vouching for the template (must authorize)
+    /// * `template_id` - The template being endorsed
+    ///
+    /// # Returns
+    /// Success, `TemplateNotFound`, or `AlreadyEndorsed`
+    pub fn endorse_template(env: Env, endorser: Address, template_id: String) -> Result<(), Error> {
+        endorser.require_auth();
+
+        if !storage::has_template(&env, &template_id) {
+            return Err(Error::TemplateNotFound);
+        }
+
+        let mut endorsers = storage::get_endorsements(&env, &template_id);
+        if endorsers.contains(&endorser) {
+            return Err(Error::AlreadyEndorsed);
+        }
+
+        endorsers.push_back(endorser.clone());
+        storage::set_endorsements(&env, &template_id, &endorsers);
+
+        events::emit_template_endorsed(&env, template_id, endorser);
+
+        Ok(())
+    }
+
+    /// Get the addresses that have endorsed a template.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template to look up
+    ///
+    /// # Returns
+    /// Endorsers in the order they endorsed; empty if none
+    pub fn get_endorsements(env: Env, template_id: String) -> Vec<Address> {
+        storage::get_endorsements(&env, &template_id)
+    }
+
+    /// Export a template as a portable XDR-encoded blob.
+    ///
+    /// The blob can be handed to `import_template` on another deployment.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `template_id` - The template ID to export
+    ///
+    /// # Returns
+    /// The XDR-encoded template or an error if not found
+    pub fn export_template(env: Env, template_id: String) -> Result<Bytes, Error> {
+        let template = storage::get_template(&env, &template_id)
+            .ok_or(Error::TemplateNotFound)?;
+
+        Ok(template.to_xdr(&env))
+    }
+
+    /// Import a template previously produced by `export_template`.
+    ///
+    /// Decodes the blob and validates and stores it exactly as `create_template`
+    /// would, owned by the caller. Template IDs are derived from the name, so the
+    /// import keeps the exported ID; importing into a deployment that already has
+    /// a template with that name fails with `TemplateAlreadyExists`. The token,
+    /// default total, decimals, and sink carry over.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address importing the template (becomes its creator)
+    /// * `blob` - XDR-encoded template bytes
+    ///
+    /// # Returns
+    /// The imported template's ID, `InvalidImport` if the blob isn't a template,
+    /// `TemplateAlreadyExists` if the name is taken, or any other error
+    /// `create_template` returns
+    pub fn import_template(env: Env, caller: Address, blob: Bytes) -> Result<String, Error> {
+        // Require authorization from the importer
+        caller.require_auth();
+
+        let imported = Template::from_xdr(&env, &blob).map_err(|_| Error::InvalidImport)?;
+
+        Self::store_new_template(
+            &env,
+            &caller,
+            imported.name,
+            imported.split_type,
+            imported.participants,
+            imported.token,
+            imported.default_total,
+            imported.decimals,
+            imported.sink,
+        )
+    }
+
+    // ============================================
+    // Private Helper Functions
+    // ============================================
+
+    /// Validate and store a new template, indexing it under its creator.
+    ///
+    /// Callers are responsible for requiring the creator's authorization.
+    #[allow(clippy::too_many_arguments)]
+    fn store_new_template(
+        env: &Env,
+        creator: &Address,
+        name: String,
+        split_type: SplitType,
+        participants: Vec<Participant>,
+        token: Option<Address>,
+        default_total: Option<i128>,
+        decimals: Option<u32>,
+        sink: Option<Address>,
+    ) -> Result<String, Error> {
+        Self::validate_name(&name)?;
+
+        // Validate that participants list is not empty
+        if participants.is_empty() {
+            return Err(Error::InvalidParticipants);
+        }
+
+        // Validate shares based on split type
+        Self::validate_shares(env, split_type, &participants)?;
+        Self::validate_sink(split_type, &sink)?;
+
+        if let Some(total) = default_total {
+            if total <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+        }
+
+        if let Some(decimals) = decimals {
+            if decimals > MAX_DECIMALS {
+                return Err(Error::InvalidDecimals);
+            }
+        }
+
+        // Generate deterministic template ID from the name
+        let template_id = Self::generate_template_id(env, creator, &name);
+
+        // Never overwrite an existing template; the caller can pick another name
+        if storage::has_template(env, &template_id) {
+            return Err(Error::TemplateAlreadyExists);
+        }
+
+        // Keep the creator index bounded; archiving frees up slots
+        let active_count = storage::get_active_template_count(env, creator);
+        if active_count >= MAX_TEMPLATES_PER_CREATOR {
+            return Err(Error::TemplateLimitReached);
+        }
+
+        // Create the template struct
+        let template = Template {
+            id: template_id.clone(),
+            creator: creator.clone(),
+            name,
+            split_type,
+            participants,
+            token,
+            default_total,
+            archived: false,
+            decimals,
+            sink,
+            created_at: env.ledger().timestamp(),
+        };
+
+        // Store the template
+        storage::store_template(env, &template);
+
+        // Add to creator's index for efficient lookup
+        storage::add_to_creator_index(env, creator, template_id.clone());
+        storage::add_to_template_index(env, template_id.clone());
+        storage::set_active_template_count(env, creator, active_count + 1);
+
+        // Emit event
+        events::emit_template_created(env, template_id.clone(), creator.clone(), template.name.clone());
+
+        Ok(template_id)
+    }
+
+    /// Generate a deterministic template ID.
+    ///
+    /// Creates a template ID from creator and name.
+    /// For simplicity, uses the name itself as the ID (must be unique per creator).
+    fn generate_template_id(_env: &Env, _creator: &Address, name: &String) -> String {
+        // Use the name itself as a simple, deterministic ID
         // In production, could add timestamp/sequence for uniqueness
         name.clone()
     }
 
+    /// Load a creator's templates from their index, optionally skipping archived ones.
+    fn load_creator_templates(env: &Env, creator: &Address, include_archived: bool) -> Vec<Template> {
+        // Get all template IDs for this creator
+        let template_ids = storage::get_creator_template_ids(env, creator);
+
+        // Load full template objects for each ID
+        let mut templates = Vec::new(env);
+        for template_id in template_ids.iter() {
+            if let Some(template) = storage::get_template(env, &template_id) {
+                if include_archived || !template.archived {
+                    templates.push_back(template);
+                }
+            }
+        }
+
+        templates
+    }
+
+    /// Resolve a template's shares into `(address, amount)` payouts for `total`.
+    fn payouts_for(
+        env: &Env,
+        template: &Template,
+        total: i128,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        if total <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let beneficiary = template
+            .sink
+            .clone()
+            .unwrap_or(template.participants.get(0).unwrap().address);
+
+        let allocated = Self::allocate_amounts(
+            env,
+            template.split_type,
+            &template.participants,
+            total,
+            &beneficiary,
+        )?;
+
+        let mut payouts = Vec::new(env);
+        for participant in allocated.iter() {
+            payouts.push_back((participant.address, participant.share));
+        }
+
+        Ok(payouts)
+    }
+
+    /// Resolve each participant's share into an amount of `total`.
+    ///
+    /// Any rounding remainder is added to `beneficiary`'s amount; a
+    /// beneficiary who isn't a participant (a sink) is appended with it.
+    fn allocate_amounts(
+        env: &Env,
+        split_type: SplitType,
+        participants: &Vec<Participant>,
+        total: i128,
+        beneficiary: &Address,
+    ) -> Result<Vec<Participant>, Error> {
+        let mut allocated = Vec::new(env);
+        let count = participants.len() as i128;
+        let mut assigned: i128 = 0;
+
+        for participant in participants.iter() {
+            let amount = match split_type {
+                SplitType::Equal => total / count,
+                SplitType::Percentage | SplitType::PercentageWithSink => {
+                    total * participant.share / 100
+                }
+                SplitType::Fixed | SplitType::FixedWithZero => participant.share,
+            };
+            assigned += amount;
+            allocated.push_back(Participant {
+                address: participant.address,
+                share: amount,
+            });
+        }
+
+        let remainder = total - assigned;
+        if remainder != 0 {
+            // Fixed shares must add up to the total exactly
+            if matches!(split_type, SplitType::Fixed | SplitType::FixedWithZero) {
+                return Err(Error::InvalidAmount);
+            }
+            match allocated.iter().position(|p| &p.address == beneficiary) {
+                Some(index) => {
+                    let mut recipient = allocated.get(index as u32).unwrap();
+                    recipient.share += remainder;
+                    allocated.set(index as u32, recipient);
+                }
+                None => allocated.push_back(Participant {
+                    address: beneficiary.clone(),
+                    share: remainder,
+                }),
+            }
+        }
+
+        Ok(allocated)
+    }
+
+    /// Sum participant shares according to split type.
+    fn shares_total(split_type: SplitType, participants: &Vec<Participant>) -> i128 {
+        match split_type {
+            // Equal shares carry no weight; each participant counts once
+            SplitType::Equal => participants.len() as i128,
+            SplitType::Percentage
+            | SplitType::PercentageWithSink
+            | SplitType::Fixed
+            | SplitType::FixedWithZero => participants.iter().map(|p| p.share).sum(),
+        }
+    }
+
+    /// Scale percentage shares proportionally so they sum to exactly 100.
+    fn normalize_percentages(
+        env: &Env,
+        participants: &Vec<Participant>,
+    ) -> Result<Vec<Participant>, Error> {
+        let mut total: i128 = 0;
+        for participant in participants.iter() {
+            if participant.share < 0 {
+                return Err(Error::InvalidShares);
+            }
+            total += participant.share;
+        }
+        if total == 0 {
+            return Err(Error::InvalidShares);
+        }
+
+        let mut normalized = Vec::new(env);
+        let mut assigned: i128 = 0;
+        for participant in participants.iter() {
+            let share = participant.share * 100 / total;
+            assigned += share;
+            normalized.push_back(Participant {
+                address: participant.address,
+                share,
+            });
+        }
+
+        // Flooring leaves at most one point per participant to hand out
+        let mut remainder = 100 - assigned;
+        let mut i = 0;
+        while remainder > 0 {
+            let mut participant = normalized.get(i).unwrap();
+            participant.share += 1;
+            normalized.set(i, participant);
+            remainder -= 1;
+            i += 1;
+        }
+
+        Ok(normalized)
+    }
+
+    /// Reject empty names and ones over `MAX_NAME_LEN` bytes.
+    fn validate_name(name: &String) -> Result<(), Error> {
+        if name.is_empty() {
+            return Err(Error::EmptyName);
+        }
+        if name.len() > MAX_NAME_LEN {
+            return Err(Error::NameTooLong);
+        }
+        Ok(())
+    }
+
+    /// Require a sink on PercentageWithSink templates and reject one elsewhere.
+    fn validate_sink(split_type: SplitType, sink: &Option<Address>) -> Result<(), Error> {
+        if (split_type == SplitType::PercentageWithSink) != sink.is_some() {
+            return Err(Error::InvalidSink);
+        }
+        Ok(())
+    }
+
     /// Validate participant shares based on split type.
     fn validate_shares(
         _env: &Env,
@@ -173,6 +1302,10 @@ impl SplitTemplateContract {
         match split_type {
             SplitType::Equal => {
                 // For equal splits, shares must all be 1 (or not checked; we trust the caller)
+                // A single-participant equal split is degenerate, so require at least two
+                if participants.len() < 2 {
+                    return Err(Error::TooFewParticipants);
+                }
                 Ok(())
             }
             SplitType::Percentage => {
@@ -189,6 +1322,20 @@ impl SplitTemplateContract {
                 }
                 Ok(())
             }
+            SplitType::PercentageWithSink => {
+                // Like Percentage, but the sink takes up whatever is left of 100
+                let mut total: i128 = 0;
+                for participant in participants.iter() {
+                    if participant.share < 0 || participant.share > 100 {
+                        return Err(Error::InvalidShares);
+                    }
+                    total += participant.share;
+                }
+                if total > 100 {
+                    return Err(Error::InvalidShares);
+                }
+                Ok(())
+            }
             SplitType::Fixed => {
                 // For fixed splits, all shares must be positive
                 for participant in participants.iter() {
@@ -198,6 +1345,15 @@ impl SplitTemplateContract {
                 }
                 Ok(())
             }
+            SplitType::FixedWithZero => {
+                // Like Fixed, but placeholders may owe nothing this round
+                for participant in participants.iter() {
+                    if participant.share < 0 {
+                        return Err(Error::InvalidShares);
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -5,7 +5,7 @@
 
 use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
-use crate::types::Template;
+use crate::types::{RecurringSchedule, Template};
 
 // Storage key types as contracted types
 #[contracttype]
@@ -20,9 +20,67 @@ pub struct CreatorKey {
     pub creator: Address,
 }
 
+#[contracttype]
+#[derive(Clone)]
+pub struct TemplateCountKey {
+    pub owner: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EndorsementKey {
+    pub template_id: String,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ContractKey {
+    Admin,
+    TemplateIndex(u32),
+    TemplateCount,
+    ScheduleCount,
+    EscrowContract,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ScheduleKey {
+    pub id: u32,
+}
+
 // Time-to-live for persistent storage (about 1 year)
 const LEDGER_TTL_PERSISTENT: u32 = 31_536_000;
 
+/// Template IDs held by each page of the global template index.
+pub const TEMPLATE_INDEX_PAGE_SIZE: u32 = 100;
+
+/// Check whether the contract admin has been set.
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&ContractKey::Admin)
+}
+
+/// Get the contract admin, if set.
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ContractKey::Admin)
+}
+
+/// Set the contract admin.
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&ContractKey::Admin, admin);
+}
+
+/// Get the escrow contract split IDs are checked against, if set.
+pub fn get_escrow_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ContractKey::EscrowContract)
+}
+
+/// Set the escrow contract split IDs are checked against.
+pub fn set_escrow_contract(env: &Env, escrow_contract: &Address) {
+    env.storage()
+        .instance()
+        .set(&ContractKey::EscrowContract, escrow_contract);
+}
+
 /// Store a template by its ID in persistent storage.
 pub fn store_template(env: &Env, template: &Template) {
     let key = TemplateKey {
@@ -42,6 +100,14 @@ pub fn get_template(env: &Env, template_id: &String) -> Option<Template> {
     env.storage().persistent().get(&key)
 }
 
+/// Check whether a template with the given ID exists.
+pub fn has_template(env: &Env, template_id: &String) -> bool {
+    let key = TemplateKey {
+        id: template_id.clone(),
+    };
+    env.storage().persistent().has(&key)
+}
+
 /// Add a template ID to a creator's index.
 pub fn add_to_creator_index(env: &Env, creator: &Address, template_id: String) {
     let key = CreatorKey {
@@ -71,3 +137,123 @@ pub fn get_creator_template_ids(env: &Env, creator: &Address) -> Vec<String> {
         .get(&key)
         .unwrap_or_else(|| Vec::new(env))
 }
+
+/// Replace a creator's index with the given template IDs.
+pub fn set_creator_template_ids(env: &Env, creator: &Address, template_ids: &Vec<String>) {
+    let key = CreatorKey {
+        creator: creator.clone(),
+    };
+    env.storage().persistent().set(&key, template_ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_PERSISTENT, LEDGER_TTL_PERSISTENT);
+}
+
+/// Append a template ID to the global index of every template ever created.
+///
+/// The index is split into pages of `TEMPLATE_INDEX_PAGE_SIZE` IDs so no
+/// single entry grows without bound.
+pub fn add_to_template_index(env: &Env, template_id: String) {
+    let count = get_template_count(env);
+    let page = count / TEMPLATE_INDEX_PAGE_SIZE;
+    let key = ContractKey::TemplateIndex(page);
+    let mut templates = get_template_index_page(env, page);
+
+    templates.push_back(template_id);
+
+    env.storage().persistent().set(&key, &templates);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_PERSISTENT, LEDGER_TTL_PERSISTENT);
+    env.storage()
+        .instance()
+        .set(&ContractKey::TemplateCount, &(count + 1));
+}
+
+/// Retrieve one page of the global template index, in creation order.
+pub fn get_template_index_page(env: &Env, page: u32) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&ContractKey::TemplateIndex(page))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get how many template IDs the global index holds.
+pub fn get_template_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ContractKey::TemplateCount)
+        .unwrap_or(0)
+}
+
+/// Allocate the next recurring schedule ID, starting from 1.
+pub fn next_schedule_id(env: &Env) -> u32 {
+    let id: u32 = env
+        .storage()
+        .instance()
+        .get(&ContractKey::ScheduleCount)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&ContractKey::ScheduleCount, &id);
+    id
+}
+
+/// Store a recurring schedule by its ID.
+pub fn set_schedule(env: &Env, schedule_id: u32, schedule: &RecurringSchedule) {
+    let key = ScheduleKey { id: schedule_id };
+    env.storage().persistent().set(&key, schedule);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_PERSISTENT, LEDGER_TTL_PERSISTENT);
+}
+
+/// Retrieve a recurring schedule by its ID.
+pub fn get_schedule(env: &Env, schedule_id: u32) -> Option<RecurringSchedule> {
+    env.storage().persistent().get(&ScheduleKey { id: schedule_id })
+}
+
+/// Remove a recurring schedule.
+pub fn remove_schedule(env: &Env, schedule_id: u32) {
+    env.storage().persistent().remove(&ScheduleKey { id: schedule_id });
+}
+
+/// Retrieve the addresses that have endorsed a template.
+pub fn get_endorsements(env: &Env, template_id: &String) -> Vec<Address> {
+    let key = EndorsementKey {
+        template_id: template_id.clone(),
+    };
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Store the endorsement list for a template.
+pub fn set_endorsements(env: &Env, template_id: &String, endorsers: &Vec<Address>) {
+    let key = EndorsementKey {
+        template_id: template_id.clone(),
+    };
+    env.storage().persistent().set(&key, endorsers);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_PERSISTENT, LEDGER_TTL_PERSISTENT);
+}
+
+/// Get how many non-archived templates a creator has.
+pub fn get_active_template_count(env: &Env, creator: &Address) -> u32 {
+    let key = TemplateCountKey {
+        owner: creator.clone(),
+    };
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set how many non-archived templates a creator has.
+pub fn set_active_template_count(env: &Env, creator: &Address, count: u32) {
+    let key = TemplateCountKey {
+        owner: creator.clone(),
+    };
+    env.storage().persistent().set(&key, &count);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LEDGER_TTL_PERSISTENT, LEDGER_TTL_PERSISTENT);
+}
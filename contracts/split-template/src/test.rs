@@ -2,10 +2,72 @@
 
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::{testutils::Address as _, Address, Env, String as SorobanString, Vec as SorobanVec};
-
-    use crate::{SplitTemplateContract, SplitTemplateContractClient};
-    use crate::types::{Participant, SplitType};
+    extern crate std;
+
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger}, xdr::ToXdr, Address, Env, String as SorobanString,
+        Vec as SorobanVec,
+    };
+
+    use crate::{SplitTemplateContract, SplitTemplateContractClient, MAX_TEMPLATES_PER_CREATOR};
+    use crate::storage;
+    use crate::types::{Error, Participant, SplitType};
+
+    mod mock_escrow {
+        extern crate std;
+
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Vec};
+
+        #[contract]
+        pub struct MockEscrow;
+
+        #[contractimpl]
+        impl MockEscrow {
+            pub fn add_escrow(env: Env, split_id: String) {
+                env.storage().persistent().set(&split_id, &true);
+            }
+
+            pub fn escrow_exists(env: Env, split_id: String) -> bool {
+                env.storage().persistent().has(&split_id)
+            }
+
+            #[allow(clippy::too_many_arguments)]
+            pub fn create_split_hashed(
+                env: Env,
+                creator: Address,
+                description: String,
+                total_amount: i128,
+                _participant_addresses: Vec<Address>,
+                participant_shares: Vec<i128>,
+                _deadline: u64,
+                _grace_period_secs: u64,
+            ) -> String {
+                creator.require_auth();
+                assert_eq!(participant_shares.iter().sum::<i128>(), total_amount);
+
+                let count = Self::escrow_count(env.clone()) + 1;
+                env.storage().instance().set(&symbol_short!("count"), &count);
+
+                let split_id = String::from_str(&env, &std::format!("escrow_{}", count));
+                env.storage().persistent().set(&(symbol_short!("creator"), split_id.clone()), &creator);
+                env.storage().persistent().set(&(symbol_short!("desc"), split_id.clone()), &description);
+                Self::add_escrow(env, split_id.clone());
+                split_id
+            }
+
+            pub fn escrow_creator(env: Env, split_id: String) -> Address {
+                env.storage().persistent().get(&(symbol_short!("creator"), split_id)).unwrap()
+            }
+
+            pub fn escrow_description(env: Env, split_id: String) -> String {
+                env.storage().persistent().get(&(symbol_short!("desc"), split_id)).unwrap()
+            }
+
+            pub fn escrow_count(env: Env) -> u32 {
+                env.storage().instance().get(&symbol_short!("count")).unwrap_or(0)
+            }
+        }
+    }
 
     fn setup() -> (Env, Address, SplitTemplateContractClient<'static>) {
         let env = Env::default();
@@ -72,6 +134,8 @@ mod tests {
             &name,
             &SplitType::Equal,
             &participants,
+            &None,
+            &None,
         );
 
         assert!(!template_id.is_empty());
@@ -90,6 +154,8 @@ mod tests {
             &name,
             &SplitType::Percentage,
             &participants,
+            &None,
+            &None,
         );
 
         assert!(!template_id.is_empty());
@@ -109,6 +175,8 @@ mod tests {
             &name,
             &SplitType::Percentage,
             &participants,
+            &None,
+            &None,
         );
     }
 
@@ -126,6 +194,8 @@ mod tests {
             &name,
             &SplitType::Percentage,
             &participants,
+            &None,
+            &None,
         );
     }
 
@@ -142,6 +212,8 @@ mod tests {
             &name,
             &SplitType::Fixed,
             &participants,
+            &None,
+            &None,
         );
 
         assert!(!template_id.is_empty());
@@ -164,7 +236,53 @@ mod tests {
             &name,
             &SplitType::Fixed,
             &participants,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_create_template_fixed_with_zero_placeholders() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Placeholder Split");
+        let participants = create_fixed_split_participants(&env, &[100, 0, 200]);
+
+        let result = client.try_create_template(
+            &creator,
+            &name,
+            &SplitType::Fixed,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result.err(), Some(Ok(Error::InvalidShares)));
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::FixedWithZero,
+            &participants,
+            &None,
+            &None,
+        );
+
+        // The placeholder owes nothing
+        let payouts = assert_payouts_sum(&client, &template_id, 300);
+        assert_eq!(payouts.get(0).unwrap().1, 100);
+        assert_eq!(payouts.get(1).unwrap().1, 0);
+        assert_eq!(payouts.get(2).unwrap().1, 200);
+
+        // Negative shares are still rejected
+        let result = client.try_create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Negative Split"),
+            &SplitType::FixedWithZero,
+            &create_fixed_split_participants(&env, &[100, -1]),
+            &None,
+            &None,
         );
+        assert_eq!(result.err(), Some(Ok(Error::InvalidShares)));
     }
 
     #[test]
@@ -180,7 +298,185 @@ mod tests {
             &name,
             &SplitType::Equal,
             &participants,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_create_template_equal_requires_two_participants() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Solo Equal");
+        let participants = create_equal_split_participants(&env, 1);
+
+        let result = client.try_create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::TooFewParticipants)));
+
+        // A single-participant Fixed template is still fine
+        let name = SorobanString::from_str(&env, "Solo Fixed");
+        let participants = create_fixed_split_participants(&env, &[100]);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Fixed,
+            &participants,
+            &None,
+            &None,
+        );
+        assert!(!template_id.is_empty());
+    }
+
+    #[test]
+    fn test_create_template_normalized_thirds() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Thirds");
+        let participants = create_percentage_split_participants(&env, &[33, 33, 33]);
+
+        // The strict entrypoint rejects shares that don't sum to 100
+        let result = client.try_create_template(
+            &creator,
+            &name,
+            &SplitType::Percentage,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidShares)));
+
+        let template_id = client.create_template_normalized(
+            &creator,
+            &name,
+            &SplitType::Percentage,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let template = client.get_template(&template_id);
+        assert_eq!(template.participants.get(0).unwrap().share, 34);
+        assert_eq!(template.participants.get(1).unwrap().share, 33);
+        assert_eq!(template.participants.get(2).unwrap().share, 33);
+    }
+
+    #[test]
+    fn test_create_template_normalized_sums_to_100() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Uneven");
+        let participants = create_percentage_split_participants(&env, &[7, 5, 3, 1]);
+
+        let template_id = client.create_template_normalized(
+            &creator,
+            &name,
+            &SplitType::Percentage,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let template = client.get_template(&template_id);
+        let total: i128 = template.participants.iter().map(|p| p.share).sum();
+        assert_eq!(total, 100);
+        assert_eq!(client.get_shares_total(&template_id), 100);
+    }
+
+    #[test]
+    fn test_create_template_name_length() {
+        let (env, creator, client) = setup();
+        let participants = create_equal_split_participants(&env, 2);
+
+        let long = "n".repeat(281);
+        let result = client.try_create_template(
+            &creator,
+            &SorobanString::from_str(&env, &long),
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::NameTooLong)));
+
+        let result = client.try_create_template(
+            &creator,
+            &SorobanString::from_str(&env, ""),
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::EmptyName)));
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Weekend Trip"),
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
         );
+        assert!(client.template_exists(&template_id));
+    }
+
+    #[test]
+    fn test_create_templates_batch() {
+        let (env, creator, client) = setup();
+
+        let mut templates = SorobanVec::new(&env);
+        templates.push_back((
+            SorobanString::from_str(&env, "Rent"),
+            SplitType::Percentage,
+            create_percentage_split_participants(&env, &[50, 50]),
+        ));
+        templates.push_back((
+            SorobanString::from_str(&env, "Utilities"),
+            SplitType::Equal,
+            create_equal_split_participants(&env, 3),
+        ));
+        templates.push_back((
+            SorobanString::from_str(&env, "Groceries"),
+            SplitType::Fixed,
+            create_fixed_split_participants(&env, &[2000, 3000]),
+        ));
+
+        let ids = client.create_templates_batch(&creator, &templates);
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids.get(0).unwrap(), SorobanString::from_str(&env, "Rent"));
+
+        let stored = client.get_templates(&creator);
+        assert_eq!(stored.len(), 3);
+        assert_eq!(stored.get(2).unwrap().split_type, SplitType::Fixed);
+    }
+
+    #[test]
+    fn test_create_templates_batch_is_atomic() {
+        let (env, creator, client) = setup();
+
+        let mut templates = SorobanVec::new(&env);
+        templates.push_back((
+            SorobanString::from_str(&env, "Valid"),
+            SplitType::Equal,
+            create_equal_split_participants(&env, 2),
+        ));
+        templates.push_back((
+            SorobanString::from_str(&env, "Invalid"),
+            SplitType::Percentage,
+            create_percentage_split_participants(&env, &[60, 60]),
+        ));
+
+        let result = client.try_create_templates_batch(&creator, &templates);
+        assert_eq!(result, Err(Ok(Error::InvalidShares)));
+        assert_eq!(client.get_templates(&creator).len(), 0);
+        assert!(!client.template_exists(&SorobanString::from_str(&env, "Valid")));
     }
 
     // ============================================
@@ -188,29 +484,35 @@ mod tests {
     // ============================================
 
     #[test]
-    fn test_deterministic_id_generation() {
+    fn test_deterministic_id_collision_rejected() {
         let (env, creator, client) = setup();
 
         let name = SorobanString::from_str(&env, "Deterministic Test");
         let participants1 = create_equal_split_participants(&env, 2);
-        let participants2 = create_equal_split_participants(&env, 2);
+        let participants2 = create_equal_split_participants(&env, 3);
 
         let id1 = client.create_template(
             &creator,
             &name,
             &SplitType::Equal,
             &participants1,
+            &None,
+            &None,
         );
 
-        let id2 = client.create_template(
+        // Same inputs in the same ledger map to the same ID, so the second is rejected
+        let result = client.try_create_template(
             &creator,
             &name,
             &SplitType::Equal,
             &participants2,
+            &None,
+            &None,
         );
+        assert_eq!(result, Err(Ok(Error::TemplateAlreadyExists)));
 
-        // IDs should be the same when created with same inputs
-        assert_eq!(id1, id2);
+        // The original template was not overwritten
+        assert_eq!(client.get_template(&id1).participants.len(), 2);
     }
 
     #[test]
@@ -227,6 +529,8 @@ mod tests {
             &name1,
             &SplitType::Equal,
             &participants1,
+            &None,
+            &None,
         );
 
         let id2 = client.create_template(
@@ -234,6 +538,8 @@ mod tests {
             &name2,
             &SplitType::Equal,
             &participants2,
+            &None,
+            &None,
         );
 
         // Different names should produce different IDs
@@ -256,6 +562,8 @@ mod tests {
             &name,
             &SplitType::Equal,
             &participants,
+            &None,
+            &None,
         );
 
         let template = client.get_template(&template_id);
@@ -276,150 +584,1234 @@ mod tests {
     }
 
     #[test]
-    fn test_get_templates_by_creator() {
+    fn test_get_template_by_name() {
         let (env, creator, client) = setup();
 
-        let name1 = SorobanString::from_str(&env, "Template 1");
-        let name2 = SorobanString::from_str(&env, "Template 2");
-        let name3 = SorobanString::from_str(&env, "Template 3");
+        let participants = create_equal_split_participants(&env, 2);
+        for name in ["Rent", "Groceries", "Utilities"] {
+            client.create_template(
+                &creator,
+                &SorobanString::from_str(&env, name),
+                &SplitType::Equal,
+                &participants,
+                &None,
+                &None,
+            );
+        }
 
-        let participants1 = create_equal_split_participants(&env, 2);
-        let participants2 = create_percentage_split_participants(&env, &[50, 50]);
-        let participants3 = create_fixed_split_participants(&env, &[100, 200]);
+        let template =
+            client.get_template_by_name(&creator, &SorobanString::from_str(&env, "Groceries"));
+        assert_eq!(template.name, SorobanString::from_str(&env, "Groceries"));
+        assert_eq!(template.creator, creator);
 
-        // Create three templates
-        client.create_template(
-            &creator,
-            &name1,
-            &SplitType::Equal,
-            &participants1,
-        );
+        let missing =
+            client.try_get_template_by_name(&creator, &SorobanString::from_str(&env, "Travel"));
+        assert_eq!(missing.err(), Some(Ok(Error::TemplateNotFound)));
 
-        client.create_template(
-            &creator,
-            &name2,
-            &SplitType::Percentage,
-            &participants2,
-        );
+        let other = Address::generate(&env);
+        let not_theirs =
+            client.try_get_template_by_name(&other, &SorobanString::from_str(&env, "Rent"));
+        assert_eq!(not_theirs.err(), Some(Ok(Error::TemplateNotFound)));
+    }
 
-        client.create_template(
-            &creator,
-            &name3,
-            &SplitType::Fixed,
-            &participants3,
-        );
+    #[test]
+    fn test_admin_list_templates() {
+        let (env, creator, client) = setup();
+        let other = Address::generate(&env);
 
-        // Retrieve all templates by creator
-        let templates = client.get_templates(&creator);
+        let admin = Address::generate(&env);
+        let result = client.try_admin_list_templates(&0, &10);
+        assert_eq!(result.err(), Some(Ok(Error::NotInitialized)));
 
-        assert_eq!(templates.len(), 3);
+        client.initialize(&admin);
+        let result = client.try_initialize(&admin);
+        assert_eq!(result.err(), Some(Ok(Error::AlreadyInitialized)));
 
-        // Verify all templates belong to the creator
-        for template in templates.iter() {
-            assert_eq!(template.creator, creator);
+        let participants = create_equal_split_participants(&env, 2);
+        let mut ids = std::vec::Vec::new();
+        for (owner, name) in [(&creator, "Rent"), (&other, "Trip"), (&creator, "Food")] {
+            ids.push(client.create_template(
+                owner,
+                &SorobanString::from_str(&env, name),
+                &SplitType::Equal,
+                &participants,
+                &None,
+                &None,
+            ));
         }
-    }
 
-    #[test]
-    fn test_get_templates_empty_for_new_creator() {
-        let (env, _, client) = setup();
+        let all = client.admin_list_templates(&0, &10);
+        assert_eq!(all.len(), 3);
+        for (i, id) in ids.iter().enumerate() {
+            assert_eq!(&all.get(i as u32).unwrap().id, id);
+        }
+        assert_eq!(all.get(1).unwrap().creator, other);
 
-        let new_creator = Address::generate(&env);
-        let templates = client.get_templates(&new_creator);
+        let page = client.admin_list_templates(&1, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, ids[1]);
 
-        assert_eq!(templates.len(), 0);
+        assert_eq!(client.admin_list_templates(&5, &10).len(), 0);
     }
 
     #[test]
-    fn test_get_templates_multiple_creators() {
-        let (env, creator1, client) = setup();
-        let creator2 = Address::generate(&env);
+    fn test_admin_list_templates_across_index_pages() {
+        let (env, creator, client) = setup();
+        client.initialize(&Address::generate(&env));
 
-        let name1 = SorobanString::from_str(&env, "Creator1 Template");
-        let name2 = SorobanString::from_str(&env, "Creator2 Template");
+        // Fill the first index page up to its last slot
+        let filler = storage::TEMPLATE_INDEX_PAGE_SIZE - 1;
+        env.as_contract(&client.address, || {
+            for _ in 0..filler {
+                storage::add_to_template_index(&env, SorobanString::from_str(&env, "gone"));
+            }
+        });
 
         let participants = create_equal_split_participants(&env, 2);
+        let mut ids = std::vec::Vec::new();
+        for name in ["Rent", "Trip"] {
+            ids.push(client.create_template(
+                &creator,
+                &SorobanString::from_str(&env, name),
+                &SplitType::Equal,
+                &participants,
+                &None,
+                &None,
+            ));
+        }
 
-        // Creator 1 creates a template
-        client.create_template(
-            &creator1,
-            &name1,
-            &SplitType::Equal,
-            &participants,
-        );
+        env.as_contract(&client.address, || {
+            assert_eq!(storage::get_template_count(&env), filler + 2);
+            assert_eq!(
+                storage::get_template_index_page(&env, 0).len(),
+                storage::TEMPLATE_INDEX_PAGE_SIZE
+            );
+            assert_eq!(storage::get_template_index_page(&env, 1).len(), 1);
+        });
+
+        // The two real templates straddle the page boundary
+        let listed = client.admin_list_templates(&filler, &10);
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed.get(0).unwrap().id, ids[0]);
+        assert_eq!(listed.get(1).unwrap().id, ids[1]);
+    }
 
-        // Creator 2 creates a template
-        client.create_template(
-            &creator2,
-            &name2,
+    #[test]
+    fn test_repair_creator_index() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Rent"),
             &SplitType::Equal,
-            &participants,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &None,
         );
 
-        // Verify separation
-        let templates1 = client.get_templates(&creator1);
-        let templates2 = client.get_templates(&creator2);
-
-        assert_eq!(templates1.len(), 1);
-        assert_eq!(templates2.len(), 1);
-        assert_eq!(templates1.get(0).unwrap().creator, creator1);
-        assert_eq!(templates2.get(0).unwrap().creator, creator2);
+        env.as_contract(&client.address, || {
+            storage::add_to_creator_index(&env, &creator, SorobanString::from_str(&env, "gone"));
+        });
+        assert_eq!(client.get_templates(&creator).len(), 1);
+
+        // Only the stored admin may rewrite an index
+        let result = client.try_repair_creator_index(&creator);
+        assert_eq!(result, Err(Ok(Error::NotInitialized)));
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+        assert_eq!(client.repair_creator_index(&creator), 1);
+        let (authorizer, _) = env.auths().first().unwrap().clone();
+        assert_eq!(authorizer, admin);
+        env.as_contract(&client.address, || {
+            let ids = storage::get_creator_template_ids(&env, &creator);
+            assert_eq!(ids.len(), 1);
+            assert_eq!(ids.get(0).unwrap(), template_id);
+        });
+
+        // A clean index is left alone
+        assert_eq!(client.repair_creator_index(&creator), 0);
     }
 
-    // ============================================
-    // Template Usage Tests
-    // ============================================
-
     #[test]
-    fn test_use_template_success() {
+    fn test_template_created_at() {
         let (env, creator, client) = setup();
-
-        let name = SorobanString::from_str(&env, "Usable Template");
-        let participants = create_equal_split_participants(&env, 2);
+        env.ledger().with_mut(|l| l.timestamp = 1_700_000_000);
 
         let template_id = client.create_template(
             &creator,
-            &name,
+            &SorobanString::from_str(&env, "Dated"),
             &SplitType::Equal,
-            &participants,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &None,
         );
 
-        let split_id = SorobanString::from_str(&env, "SPLIT_001");
-        let _ = client.use_template(&template_id, &split_id);
+        env.ledger().with_mut(|l| l.timestamp = 1_700_000_500);
+        let template = client.get_template(&template_id);
+        assert_eq!(template.created_at, 1_700_000_000);
     }
 
     #[test]
-    #[should_panic]
-    fn test_use_template_not_found() {
-        let (env, _creator, client) = setup();
+    fn test_recurring_schedule_runs_each_interval() {
+        let (env, creator, client) = setup();
+        // The owner authorizes the escrow call nested under trigger_recurring
+        env.mock_all_auths_allowing_non_root_auth();
+        client.initialize(&Address::generate(&env));
+        let escrow_id = env.register_contract(None, mock_escrow::MockEscrow);
+        let escrow = mock_escrow::MockEscrowClient::new(&env, &escrow_id);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
 
-        let fake_template_id = SorobanString::from_str(&env, "NONEXISTENT_TEMPLATE");
-        let split_id = SorobanString::from_str(&env, "SPLIT_001");
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Subscription"),
+            &SplitType::Equal,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &None,
+        );
+        let token = Address::generate(&env);
+        let schedule_id = client.schedule_recurring(&template_id, &3_600, &100, &token);
 
-        let _ = client.use_template(&fake_template_id, &split_id);
+        let result = client.try_trigger_recurring(&schedule_id);
+        assert_eq!(result.err(), Some(Ok(Error::ScheduleNotDue)));
+
+        // Runs need an escrow contract to create splits in
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_600);
+        let result = client.try_trigger_recurring(&schedule_id);
+        assert_eq!(result.err(), Some(Ok(Error::EscrowNotLinked)));
+
+        client.set_escrow_contract(&escrow_id);
+        let first = client.trigger_recurring(&schedule_id);
+
+        // The next run is measured from the last one
+        let result = client.try_trigger_recurring(&schedule_id);
+        assert_eq!(result.err(), Some(Ok(Error::ScheduleNotDue)));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + 7_200);
+        let second = client.trigger_recurring(&schedule_id);
+
+        assert_eq!(escrow.escrow_count(), 2);
+        assert!(first != second);
+        assert!(escrow.escrow_exists(&first));
+        assert!(escrow.escrow_exists(&second));
+
+        // The template creator owns each run's escrow, and runs are described apart
+        assert_eq!(escrow.escrow_creator(&first), creator);
+        assert_eq!(
+            escrow.escrow_description(&first),
+            SorobanString::from_str(&env, "Subscription #1.1")
+        );
+        assert_eq!(
+            escrow.escrow_description(&second),
+            SorobanString::from_str(&env, "Subscription #1.2")
+        );
+
+        let schedule = client.get_recurring_schedule(&schedule_id);
+        assert_eq!(schedule.runs, 2);
+        assert_eq!(schedule.last_run, 1_000 + 7_200);
+
+        let result = client.try_schedule_recurring(&template_id, &0, &100, &token);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidInterval)));
+
+        // Archived templates can't be scheduled
+        client.archive_all_templates(&creator);
+        let result = client.try_schedule_recurring(&template_id, &3_600, &100, &token);
+        assert_eq!(result.err(), Some(Ok(Error::TemplateArchived)));
     }
 
     #[test]
-    fn test_use_template_emits_event() {
+    fn test_recurring_schedule_stops_when_archived_or_cancelled() {
         let (env, creator, client) = setup();
+        // The owner authorizes the escrow call nested under trigger_recurring
+        env.mock_all_auths_allowing_non_root_auth();
+        client.initialize(&Address::generate(&env));
+        let escrow_id = env.register_contract(None, mock_escrow::MockEscrow);
+        let escrow = mock_escrow::MockEscrowClient::new(&env, &escrow_id);
+        client.set_escrow_contract(&escrow_id);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
 
-        let name = SorobanString::from_str(&env, "Event Template");
-        let participants = create_equal_split_participants(&env, 2);
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Subscription"),
+            &SplitType::Equal,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &None,
+        );
+        let token = Address::generate(&env);
+        let first_id = client.schedule_recurring(&template_id, &3_600, &100, &token);
+        let second_id = client.schedule_recurring(&template_id, &3_600, &100, &token);
+
+        // Two schedules of one template running in the same ledger get distinct escrows
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_600);
+        let first = client.trigger_recurring(&first_id);
+        let second = client.trigger_recurring(&second_id);
+        assert!(escrow.escrow_description(&first) != escrow.escrow_description(&second));
+
+        // Only the owner can cancel, and a cancelled schedule is gone
+        client.cancel_recurring(&first_id);
+        assert_eq!(
+            env.auths().first().map(|(address, _)| address.clone()),
+            Some(creator.clone())
+        );
+        let result = client.try_get_recurring_schedule(&first_id);
+        assert_eq!(result.err(), Some(Ok(Error::ScheduleNotFound)));
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + 7_200);
+        let result = client.try_trigger_recurring(&first_id);
+        assert_eq!(result.err(), Some(Ok(Error::ScheduleNotFound)));
+
+        // Archiving the template stops schedules made before it
+        client.archive_all_templates(&creator);
+        let result = client.try_trigger_recurring(&second_id);
+        assert_eq!(result.err(), Some(Ok(Error::TemplateArchived)));
+        assert_eq!(escrow.escrow_count(), 2);
+    }
+
+    #[test]
+    fn test_use_template_checks_split_in_linked_escrow() {
+        let (env, creator, client) = setup();
+        client.initialize(&Address::generate(&env));
 
         let template_id = client.create_template(
             &creator,
-            &name,
+            &SorobanString::from_str(&env, "Checked"),
             &SplitType::Equal,
-            &participants,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &None,
         );
 
-        let split_id = SorobanString::from_str(&env, "SPLIT_EVENT_TEST");
+        // Without an escrow link the split ID is trusted as before
+        let bogus = SorobanString::from_str(&env, "no_such_split");
+        client.use_template(&template_id, &bogus);
 
-        // Use the template and emit event
-        let _ = client.use_template(&template_id, &split_id);
+        let escrow_id = env.register_contract(None, mock_escrow::MockEscrow);
+        let escrow = mock_escrow::MockEscrowClient::new(&env, &escrow_id);
+        client.set_escrow_contract(&escrow_id);
 
-        // In practice, you'd verify the event was emitted
-        // This is a smoke test that the function completes
+        let result = client.try_use_template(&template_id, &bogus);
+        assert_eq!(result.err(), Some(Ok(Error::SplitNotFound)));
+
+        let real = SorobanString::from_str(&env, "esc_1");
+        escrow.add_escrow(&real);
+        client.use_template(&template_id, &real);
+    }
+
+    #[test]
+    fn test_merge_fixed_templates() {
+        let (env, creator, client) = setup();
+
+        let core = SorobanVec::from_array(&env, [create_participant(&env, 300), create_participant(&env, 200)]);
+        let shared = create_participant(&env, 100);
+        let mut advisors = SorobanVec::new(&env);
+        advisors.push_back(create_participant(&env, 50));
+        advisors.push_back(shared.clone());
+        let mut core_with_shared = core.clone();
+        core_with_shared.push_back(shared.clone());
+
+        let id_a = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Core"),
+            &SplitType::Fixed,
+            &core_with_shared,
+            &None,
+            &None,
+        );
+        let id_b = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Advisors"),
+            &SplitType::Fixed,
+            &advisors,
+            &None,
+            &None,
+        );
+
+        let merger = Address::generate(&env);
+        let merged_id = client.merge_templates(
+            &merger,
+            &id_a,
+            &id_b,
+            &SorobanString::from_str(&env, "Everyone"),
+            &SplitType::Fixed,
+        );
+
+        let merged = client.get_template(&merged_id);
+        assert_eq!(merged.creator, merger);
+        assert_eq!(merged.participants.len(), 4);
+        assert_eq!(merged.participants.get(0).unwrap().share, 300);
+        assert_eq!(merged.participants.get(1).unwrap().share, 200);
+        // An address in both templates keeps the sum of its shares
+        assert_eq!(merged.participants.get(2).unwrap().address, shared.address);
+        assert_eq!(merged.participants.get(2).unwrap().share, 200);
+        assert_eq!(merged.participants.get(3).unwrap().share, 50);
+
+        // Fixed amounts don't sum to 100, so a Percentage merge is rejected
+        let result = client.try_merge_templates(
+            &merger,
+            &id_a,
+            &id_b,
+            &SorobanString::from_str(&env, "Everyone %"),
+            &SplitType::Percentage,
+        );
+        assert_eq!(result.err(), Some(Ok(Error::InvalidShares)));
+    }
+
+    #[test]
+    fn test_template_exists() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Existing Template");
+        let participants = create_equal_split_participants(&env, 2);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        assert!(client.template_exists(&template_id));
+        assert!(!client.template_exists(&SorobanString::from_str(&env, "RANDOM_ID")));
+    }
+
+    #[test]
+    fn test_get_templates_by_creator() {
+        let (env, creator, client) = setup();
+
+        let name1 = SorobanString::from_str(&env, "Template 1");
+        let name2 = SorobanString::from_str(&env, "Template 2");
+        let name3 = SorobanString::from_str(&env, "Template 3");
+
+        let participants1 = create_equal_split_participants(&env, 2);
+        let participants2 = create_percentage_split_participants(&env, &[50, 50]);
+        let participants3 = create_fixed_split_participants(&env, &[100, 200]);
+
+        // Create three templates
+        client.create_template(
+            &creator,
+            &name1,
+            &SplitType::Equal,
+            &participants1,
+            &None,
+            &None,
+        );
+
+        client.create_template(
+            &creator,
+            &name2,
+            &SplitType::Percentage,
+            &participants2,
+            &None,
+            &None,
+        );
+
+        client.create_template(
+            &creator,
+            &name3,
+            &SplitType::Fixed,
+            &participants3,
+            &None,
+            &None,
+        );
+
+        // Retrieve all templates by creator
+        let templates = client.get_templates(&creator);
+
+        assert_eq!(templates.len(), 3);
+
+        // Verify all templates belong to the creator
+        for template in templates.iter() {
+            assert_eq!(template.creator, creator);
+        }
+    }
+
+    #[test]
+    fn test_get_templates_by_type() {
+        let (env, creator, client) = setup();
+
+        client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Equal"),
+            &SplitType::Equal,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &None,
+        );
+        client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Percentage"),
+            &SplitType::Percentage,
+            &create_percentage_split_participants(&env, &[60, 40]),
+            &None,
+            &None,
+        );
+        let fixed_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Fixed"),
+            &SplitType::Fixed,
+            &create_fixed_split_participants(&env, &[500, 300]),
+            &None,
+            &None,
+        );
+
+        let fixed = client.get_templates_by_type(&creator, &SplitType::Fixed);
+        assert_eq!(fixed.len(), 1);
+        assert_eq!(fixed.get(0).unwrap().id, fixed_id);
+        assert_eq!(fixed.get(0).unwrap().split_type, SplitType::Fixed);
+    }
+
+    #[test]
+    fn test_get_templates_empty_for_new_creator() {
+        let (env, _, client) = setup();
+
+        let new_creator = Address::generate(&env);
+        let templates = client.get_templates(&new_creator);
+
+        assert_eq!(templates.len(), 0);
+    }
+
+    #[test]
+    fn test_get_templates_multiple_creators() {
+        let (env, creator1, client) = setup();
+        let creator2 = Address::generate(&env);
+
+        let name1 = SorobanString::from_str(&env, "Creator1 Template");
+        let name2 = SorobanString::from_str(&env, "Creator2 Template");
+
+        let participants = create_equal_split_participants(&env, 2);
+
+        // Creator 1 creates a template
+        client.create_template(
+            &creator1,
+            &name1,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        // Creator 2 creates a template
+        client.create_template(
+            &creator2,
+            &name2,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        // Verify separation
+        let templates1 = client.get_templates(&creator1);
+        let templates2 = client.get_templates(&creator2);
+
+        assert_eq!(templates1.len(), 1);
+        assert_eq!(templates2.len(), 1);
+        assert_eq!(templates1.get(0).unwrap().creator, creator1);
+        assert_eq!(templates2.get(0).unwrap().creator, creator2);
+    }
+
+    #[test]
+    fn test_diff_templates() {
+        let (env, creator, client) = setup();
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        let dave = Address::generate(&env);
+
+        let mut before = SorobanVec::new(&env);
+        before.push_back(Participant { address: alice.clone(), share: 300 });
+        before.push_back(Participant { address: bob.clone(), share: 300 });
+        before.push_back(Participant { address: carol.clone(), share: 400 });
+
+        let mut after = SorobanVec::new(&env);
+        after.push_back(Participant { address: alice.clone(), share: 300 });
+        after.push_back(Participant { address: bob.clone(), share: 200 });
+        after.push_back(Participant { address: dave.clone(), share: 500 });
+
+        let id_a = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Before"),
+            &SplitType::Fixed,
+            &before,
+            &None,
+            &None,
+        );
+        let id_b = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "After"),
+            &SplitType::Fixed,
+            &after,
+            &None,
+            &None,
+        );
+
+        let diff = client.diff_templates(&id_a, &id_b);
+        assert!(diff.same_split_type);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added.get(0).unwrap(), dave);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed.get(0).unwrap(), carol);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed.get(0).unwrap(), (bob, 300, 200));
+
+        let result = client.try_diff_templates(&id_a, &SorobanString::from_str(&env, "Missing"));
+        assert_eq!(result.err(), Some(Ok(Error::TemplateNotFound)));
+    }
+
+    #[test]
+    fn test_get_shares_total_equal() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Equal Total");
+        let participants = create_equal_split_participants(&env, 4);
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        assert_eq!(client.get_shares_total(&template_id), 4);
+    }
+
+    #[test]
+    fn test_fairness_report() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Lopsided"),
+            &SplitType::Percentage,
+            &create_percentage_split_participants(&env, &[50, 30, 20]),
+            &None,
+            &None,
+        );
+        assert_eq!(client.fairness_report(&template_id), (50, 20, 30));
+
+        let equal_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Even"),
+            &SplitType::Equal,
+            &create_equal_split_participants(&env, 3),
+            &None,
+            &None,
+        );
+        assert_eq!(client.fairness_report(&equal_id), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_get_shares_total_percentage() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Percentage Total");
+        let participants = create_percentage_split_participants(&env, &[60, 25, 15]);
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Percentage,
+            &participants,
+            &None,
+            &None,
+        );
+
+        assert_eq!(client.get_shares_total(&template_id), 100);
+    }
+
+    #[test]
+    fn test_get_shares_total_fixed() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Fixed Total");
+        let participants = create_fixed_split_participants(&env, &[1000, 2500, 500]);
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Fixed,
+            &participants,
+            &None,
+            &None,
+        );
+
+        assert_eq!(client.get_shares_total(&template_id), 4000);
+    }
+
+    #[test]
+    fn test_format_share_with_decimals() {
+        let (env, creator, client) = setup();
+
+        let participants = create_fixed_split_participants(&env, &[100_0000000, 5]);
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Stellar Dinner"),
+            &SplitType::Fixed,
+            &participants,
+            &None,
+            &None,
+        );
+
+        // Without decimals the raw share is shown
+        assert_eq!(
+            client.format_share(&template_id, &0),
+            SorobanString::from_str(&env, "1000000000")
+        );
+
+        client.set_template_decimals(&template_id, &7);
+        assert_eq!(client.get_template(&template_id).decimals, Some(7));
+        assert_eq!(
+            client.format_share(&template_id, &0),
+            SorobanString::from_str(&env, "100.0000000")
+        );
+        assert_eq!(
+            client.format_share(&template_id, &1),
+            SorobanString::from_str(&env, "0.0000005")
+        );
+
+        let result = client.try_format_share(&template_id, &2);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidIndex)));
+
+        let result = client.try_set_template_decimals(&template_id, &39);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidDecimals)));
+    }
+
+    #[test]
+    fn test_archive_all_templates() {
+        let (env, creator, client) = setup();
+
+        for name_str in ["Archive 0", "Archive 1", "Archive 2"].iter() {
+            let name = SorobanString::from_str(&env, name_str);
+            let participants = create_equal_split_participants(&env, 2);
+            client.create_template(
+                &creator,
+                &name,
+                &SplitType::Equal,
+                &participants,
+                &None,
+                &None,
+            );
+        }
+        assert_eq!(client.get_templates(&creator).len(), 3);
+
+        assert_eq!(client.archive_all_templates(&creator), 3);
+
+        assert_eq!(client.get_templates(&creator).len(), 0);
+        let all = client.get_templates_including_archived(&creator);
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().all(|t| t.archived));
+
+        // Nothing left to archive
+        assert_eq!(client.archive_all_templates(&creator), 0);
+    }
+
+    #[test]
+    fn test_template_limit_per_creator() {
+        let (env, creator, client) = setup();
+        env.budget().reset_unlimited();
+
+        let participants = create_equal_split_participants(&env, 2);
+        for i in 0..MAX_TEMPLATES_PER_CREATOR {
+            let name = SorobanString::from_str(&env, &std::format!("Template {}", i));
+            client.create_template(
+                &creator,
+                &name,
+                &SplitType::Equal,
+                &participants,
+                &None,
+                &None,
+            );
+        }
+
+        let overflow = SorobanString::from_str(&env, "One Too Many");
+        let result = client.try_create_template(
+            &creator,
+            &overflow,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::TemplateLimitReached)));
+
+        // Archiving frees up slots
+        client.archive_all_templates(&creator);
+        client.create_template(
+            &creator,
+            &overflow,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+    }
+
+    // ============================================
+    // Template Usage Tests
+    // ============================================
+
+    #[test]
+    fn test_use_template_success() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Usable Template");
+        let participants = create_equal_split_participants(&env, 2);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let split_id = SorobanString::from_str(&env, "SPLIT_001");
+        let _ = client.use_template(&template_id, &split_id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_use_template_not_found() {
+        let (env, _creator, client) = setup();
+
+        let fake_template_id = SorobanString::from_str(&env, "NONEXISTENT_TEMPLATE");
+        let split_id = SorobanString::from_str(&env, "SPLIT_001");
+
+        let _ = client.use_template(&fake_template_id, &split_id);
+    }
+
+    #[test]
+    fn test_use_template_emits_event() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Event Template");
+        let participants = create_equal_split_participants(&env, 2);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let split_id = SorobanString::from_str(&env, "SPLIT_EVENT_TEST");
+
+        // Use the template and emit event
+        let _ = client.use_template(&template_id, &split_id);
+
+        // In practice, you'd verify the event was emitted
+        // This is a smoke test that the function completes
+    }
+
+    #[test]
+    fn test_apply_template_uses_defaults() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Monthly Subscription");
+        let participants = create_percentage_split_participants(&env, &[50, 30, 20]);
+        let token = Address::generate(&env);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Percentage,
+            &participants,
+            &Some(token.clone()),
+            &Some(1000),
+        );
+
+        let draft = client.apply_template_to_escrow(&template_id, &None, &None, &None);
+        assert_eq!(draft.token, token);
+        assert_eq!(draft.total_amount, 1000);
+        assert_eq!(draft.participants.get(0).unwrap().share, 500);
+        assert_eq!(draft.participants.get(1).unwrap().share, 300);
+        assert_eq!(draft.participants.get(2).unwrap().share, 200);
+
+        // Explicit values override the defaults
+        let draft = client.apply_template_to_escrow(&template_id, &None, &Some(2000), &None);
+        assert_eq!(draft.total_amount, 2000);
+        assert_eq!(draft.participants.get(0).unwrap().share, 1000);
+    }
+
+    #[test]
+    fn test_percentage_with_sink() {
+        let (env, creator, client) = setup();
+
+        let participants = create_percentage_split_participants(&env, &[50, 30]);
+        let sink = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        let template_id = client.create_template_with_sink(
+            &creator,
+            &SorobanString::from_str(&env, "Team Payout"),
+            &participants,
+            &sink,
+            &Some(token),
+            &Some(1000),
+        );
+
+        let template = client.get_template(&template_id);
+        assert_eq!(template.split_type, SplitType::PercentageWithSink);
+        assert_eq!(template.sink, Some(sink.clone()));
+
+        // The sink captures the unallocated 20%
+        let draft = client.apply_template_to_escrow(&template_id, &None, &None, &None);
+        assert_eq!(draft.participants.len(), 3);
+        assert_eq!(draft.participants.get(0).unwrap().share, 500);
+        assert_eq!(draft.participants.get(1).unwrap().share, 300);
+        let sink_entry = draft.participants.get(2).unwrap();
+        assert_eq!(sink_entry.address, sink);
+        assert_eq!(sink_entry.share, 200);
+        assert_eq!(draft.rounding_beneficiary, sink);
+
+        // Participants may not claim more than 100%
+        let over = create_percentage_split_participants(&env, &[60, 50]);
+        let result = client.try_create_template_with_sink(
+            &creator,
+            &SorobanString::from_str(&env, "Over"),
+            &over,
+            &sink,
+            &None,
+            &None,
+        );
+        assert_eq!(result.err(), Some(Ok(Error::InvalidShares)));
+
+        // The sink type can't be created without a sink
+        let result = client.try_create_template(
+            &creator,
+            &SorobanString::from_str(&env, "No Sink"),
+            &SplitType::PercentageWithSink,
+            &participants,
+            &None,
+            &None,
+        );
+        assert_eq!(result.err(), Some(Ok(Error::InvalidSink)));
+    }
+
+    fn assert_payouts_sum(
+        client: &SplitTemplateContractClient,
+        template_id: &SorobanString,
+        total: i128,
+    ) -> SorobanVec<(Address, i128)> {
+        let payouts = client.compute_payouts(template_id, &total);
+        let sum: i128 = payouts.iter().map(|(_, amount)| amount).sum();
+        assert_eq!(sum, total);
+        payouts
+    }
+
+    #[test]
+    fn test_compute_payouts_equal() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Equal"),
+            &SplitType::Equal,
+            &create_equal_split_participants(&env, 3),
+            &None,
+            &None,
+        );
+
+        let payouts = assert_payouts_sum(&client, &template_id, 100);
+        assert_eq!(payouts.get(0).unwrap().1, 34);
+        assert_eq!(payouts.get(1).unwrap().1, 33);
+        assert_eq!(payouts.get(2).unwrap().1, 33);
+    }
+
+    #[test]
+    fn test_compute_payouts_percentage() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Percentage"),
+            &SplitType::Percentage,
+            &create_percentage_split_participants(&env, &[33, 33, 34]),
+            &None,
+            &None,
+        );
+
+        let payouts = assert_payouts_sum(&client, &template_id, 1001);
+        assert_eq!(payouts.get(0).unwrap().1, 331);
+        assert_eq!(payouts.get(1).unwrap().1, 330);
+        assert_eq!(payouts.get(2).unwrap().1, 340);
+    }
+
+    #[test]
+    fn test_preview_split() {
+        let (env, creator, client) = setup();
+
+        let participants = create_percentage_split_participants(&env, &[25, 75]);
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Preview"),
+            &SplitType::Percentage,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let (template, payouts) = client.preview_split(&template_id, &1000);
+        assert_eq!(template.id, template_id);
+        assert_eq!(template.split_type, SplitType::Percentage);
+        assert_eq!(payouts.len(), 2);
+        assert_eq!(payouts.get(0).unwrap(), (participants.get(0).unwrap().address, 250));
+        assert_eq!(payouts.get(1).unwrap(), (participants.get(1).unwrap().address, 750));
+
+        let result = client.try_preview_split(&template_id, &0);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_compute_payouts_fixed() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Fixed"),
+            &SplitType::Fixed,
+            &create_fixed_split_participants(&env, &[700, 300]),
+            &None,
+            &None,
+        );
+
+        let payouts = assert_payouts_sum(&client, &template_id, 1000);
+        assert_eq!(payouts.get(0).unwrap().1, 700);
+        assert_eq!(payouts.get(1).unwrap().1, 300);
+
+        // Fixed shares can't be stretched to a different total
+        let result = client.try_compute_payouts(&template_id, &999);
+        assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_compute_payouts_percentage_with_sink() {
+        let (env, creator, client) = setup();
+
+        let sink = Address::generate(&env);
+        let template_id = client.create_template_with_sink(
+            &creator,
+            &SorobanString::from_str(&env, "Sink"),
+            &create_percentage_split_participants(&env, &[45, 45]),
+            &sink,
+            &None,
+            &None,
+        );
+
+        let payouts = assert_payouts_sum(&client, &template_id, 999);
+        assert_eq!(payouts.get(0).unwrap().1, 449);
+        assert_eq!(payouts.get(1).unwrap().1, 449);
+        assert_eq!(payouts.get(2).unwrap(), (sink, 101));
+    }
+
+    #[test]
+    fn test_apply_template_without_defaults_fails() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "No Defaults");
+        let participants = create_equal_split_participants(&env, 3);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let result = client.try_apply_template_to_escrow(&template_id, &None, &Some(100), &None);
+        assert_eq!(result.err(), Some(Ok(Error::MissingEscrowDefaults)));
+
+        // Remainder of an uneven equal split goes to the first participant
+        let token = Address::generate(&env);
+        let draft = client.apply_template_to_escrow(&template_id, &Some(token), &Some(100), &None);
+        assert_eq!(draft.participants.get(0).unwrap().share, 34);
+        assert_eq!(draft.participants.get(1).unwrap().share, 33);
+        assert_eq!(draft.participants.get(2).unwrap().share, 33);
+    }
+
+    #[test]
+    fn test_apply_template_rounding_beneficiary() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Three Ways");
+        let participants = create_equal_split_participants(&env, 3);
+        let beneficiary = participants.get(2).unwrap().address;
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &Some(Address::generate(&env)),
+            &Some(100),
+        );
+
+        let draft = client.apply_template_to_escrow(
+            &template_id,
+            &None,
+            &None,
+            &Some(beneficiary.clone()),
+        );
+        assert_eq!(draft.rounding_beneficiary, beneficiary);
+        assert_eq!(draft.participants.get(0).unwrap().share, 33);
+        assert_eq!(draft.participants.get(1).unwrap().share, 33);
+        assert_eq!(draft.participants.get(2).unwrap().share, 34);
+
+        // Beneficiary must be one of the participants
+        let result = client.try_apply_template_to_escrow(
+            &template_id,
+            &None,
+            &None,
+            &Some(Address::generate(&env)),
+        );
+        assert_eq!(result.err(), Some(Ok(Error::InvalidParticipants)));
+    }
+
+    // ============================================
+    // Endorsement Tests
+    // ============================================
+
+    #[test]
+    fn test_endorse_template() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Trusted Template");
+        let participants = create_equal_split_participants(&env, 2);
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Equal,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let endorser1 = Address::generate(&env);
+        let endorser2 = Address::generate(&env);
+        client.endorse_template(&endorser1, &template_id);
+        client.endorse_template(&endorser2, &template_id);
+
+        let endorsements = client.get_endorsements(&template_id);
+        assert_eq!(endorsements.len(), 2);
+        assert_eq!(endorsements.get(0).unwrap(), endorser1);
+        assert_eq!(endorsements.get(1).unwrap(), endorser2);
+
+        let result = client.try_endorse_template(&endorser1, &template_id);
+        assert_eq!(result, Err(Ok(Error::AlreadyEndorsed)));
+        assert_eq!(client.get_endorsements(&template_id).len(), 2);
+    }
+
+    // ============================================
+    // Import / Export Tests
+    // ============================================
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let (env, creator, client) = setup();
+
+        let name = SorobanString::from_str(&env, "Portable Template");
+        let participants = create_percentage_split_participants(&env, &[60, 40]);
+
+        let template_id = client.create_template(
+            &creator,
+            &name,
+            &SplitType::Percentage,
+            &participants,
+            &None,
+            &None,
+        );
+
+        let blob = client.export_template(&template_id);
+
+        // Import into a second, independent deployment
+        let other_id = env.register_contract(None, SplitTemplateContract);
+        let other_client = SplitTemplateContractClient::new(&env, &other_id);
+        let importer = Address::generate(&env);
+
+        let imported_id = other_client.import_template(&importer, &blob);
+        let imported = other_client.get_template(&imported_id);
+
+        assert_eq!(imported_id, template_id);
+        assert_eq!(imported.creator, importer);
+        assert_eq!(imported.name, name);
+        assert_eq!(imported.split_type, SplitType::Percentage);
+        assert_eq!(imported.participants, participants);
+        assert_eq!(other_client.get_templates(&importer).len(), 1);
+    }
+
+    #[test]
+    fn test_import_into_same_deployment_rejects_taken_name() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Portable Template"),
+            &SplitType::Percentage,
+            &create_percentage_split_participants(&env, &[60, 40]),
+            &None,
+            &None,
+        );
+
+        let blob = client.export_template(&template_id);
+        let importer = Address::generate(&env);
+        let result = client.try_import_template(&importer, &blob);
+
+        assert_eq!(result, Err(Ok(Error::TemplateAlreadyExists)));
+        assert_eq!(client.get_templates(&importer).len(), 0);
+    }
+
+    #[test]
+    fn test_import_rejects_non_template_blob() {
+        let (env, creator, client) = setup();
+
+        let blob = 42i128.to_xdr(&env);
+        let result = client.try_import_template(&creator, &blob);
+
+        assert_eq!(result, Err(Ok(Error::InvalidImport)));
+    }
+
+    #[test]
+    fn test_import_validates_defaults_and_decimals() {
+        let (env, creator, client) = setup();
+
+        let template_id = client.create_template(
+            &creator,
+            &SorobanString::from_str(&env, "Portable"),
+            &SplitType::Equal,
+            &create_equal_split_participants(&env, 2),
+            &None,
+            &Some(1_000),
+        );
+        client.set_template_decimals(&template_id, &2);
+        let template = client.get_template(&template_id);
+
+        let other_id = env.register_contract(None, SplitTemplateContract);
+        let other_client = SplitTemplateContractClient::new(&env, &other_id);
+        let importer = Address::generate(&env);
+
+        let mut bad_total = template.clone();
+        bad_total.default_total = Some(0);
+        let result = other_client.try_import_template(&importer, &bad_total.to_xdr(&env));
+        assert_eq!(result.err(), Some(Ok(Error::InvalidAmount)));
+
+        let mut bad_decimals = template.clone();
+        bad_decimals.decimals = Some(crate::MAX_DECIMALS + 1);
+        let result = other_client.try_import_template(&importer, &bad_decimals.to_xdr(&env));
+        assert_eq!(result.err(), Some(Ok(Error::InvalidDecimals)));
+
+        // Defaults and decimals carry over on a valid import
+        let imported_id = other_client.import_template(&importer, &client.export_template(&template_id));
+        let imported = other_client.get_template(&imported_id);
+        assert_eq!(imported.default_total, Some(1_000));
+        assert_eq!(imported.decimals, Some(2));
     }
 
     // ============================================
@@ -442,6 +1834,8 @@ mod tests {
             &name,
             &SplitType::Equal,
             &participants,
+            &None,
+            &None,
         );
 
         // The test framework handles auth; this verifies the contract compiles
@@ -469,6 +1863,8 @@ mod tests {
             &name,
             &SplitType::Equal,
             &participants,
+            &None,
+            &None,
         );
 
         let template = client.get_template(&template_id);
@@ -497,6 +1893,8 @@ mod tests {
                 &name,
                 &SplitType::Equal,
                 &participants,
+                &None,
+                &None,
             );
         }
 
@@ -14,6 +14,10 @@ pub enum SplitType {
     Percentage = 1,
     /// Split by fixed amounts
     Fixed = 2,
+    /// Split by percentage (shares sum to at most 100); the rest goes to the sink
+    PercentageWithSink = 3,
+    /// Split by fixed amounts; zero-share placeholder participants are allowed
+    FixedWithZero = 4,
 }
 
 /// A participant in a split template with their share/allocation.
@@ -22,7 +26,7 @@ pub enum SplitType {
 pub struct Participant {
     /// The participant's Stellar address
     pub address: Address,
-    /// Share value: for Equal type, meaningless; for Percentage, 0-100; for Fixed, amount
+    /// Share value: for Equal type, meaningless; for Percentage, 0-100; for Fixed and FixedWithZero, amount
     pub share: i128,
 }
 
@@ -40,6 +44,68 @@ pub struct Template {
     pub split_type: SplitType,
     /// List of participants and their shares
     pub participants: Vec<Participant>,
+    /// Default token for escrows created from this template
+    pub token: Option<Address>,
+    /// Default total amount for escrows created from this template
+    pub default_total: Option<i128>,
+    /// Archived templates are hidden from default listings
+    pub archived: bool,
+    /// Decimal places the shares are expressed in, for display
+    pub decimals: Option<u32>,
+    /// Receives whatever PercentageWithSink participants don't claim
+    pub sink: Option<Address>,
+    /// Ledger timestamp when the template was created or imported
+    pub created_at: u64,
+}
+
+/// Escrow parameters resolved from a template, ready to create an escrow with.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EscrowDraft {
+    /// The template these parameters were derived from
+    pub template_id: String,
+    /// Token the escrow is denominated in
+    pub token: Address,
+    /// Total amount to be collected
+    pub total_amount: i128,
+    /// Participants with `share` resolved to the amount each owes
+    pub participants: Vec<Participant>,
+    /// Participant who absorbs any rounding remainder
+    pub rounding_beneficiary: Address,
+}
+
+/// A template instantiated on a fixed cadence, e.g. for subscriptions.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecurringSchedule {
+    /// The template each run is drafted from
+    pub template_id: String,
+    /// The template's creator, who creates each run's escrow and may cancel
+    pub owner: Address,
+    /// Minimum seconds between runs
+    pub interval_secs: u64,
+    /// Total amount of each run's escrow
+    pub total_amount: i128,
+    /// Token each run's escrow is denominated in
+    pub token: Address,
+    /// Ledger timestamp of the last run, or of scheduling before the first
+    pub last_run: u64,
+    /// How many times the schedule has run
+    pub runs: u32,
+}
+
+/// How one template differs from another.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TemplateDiff {
+    /// Whether both templates use the same split type
+    pub same_split_type: bool,
+    /// Participants in the second template but not the first
+    pub added: Vec<Address>,
+    /// Participants in the first template but not the second
+    pub removed: Vec<Address>,
+    /// Participants in both whose share differs, as `(address, share_a, share_b)`
+    pub changed: Vec<(Address, i128, i128)>,
 }
 
 /// Contract errors
@@ -53,4 +119,44 @@ pub enum Error {
     InvalidParticipants = 2,
     /// Shares are invalid for the given split type
     InvalidShares = 3,
+    /// Imported blob does not decode to a template
+    InvalidImport = 4,
+    /// Equal split has fewer than two participants
+    TooFewParticipants = 5,
+    /// Token or amount was neither supplied nor defaulted on the template
+    MissingEscrowDefaults = 6,
+    /// Total amount is not positive or doesn't match fixed shares
+    InvalidAmount = 7,
+    /// Address has already endorsed this template
+    AlreadyEndorsed = 8,
+    /// Template name is empty
+    EmptyName = 9,
+    /// Template name exceeds the maximum length
+    NameTooLong = 10,
+    /// A template already exists under the generated ID
+    TemplateAlreadyExists = 11,
+    /// Creator already has the maximum number of active templates
+    TemplateLimitReached = 12,
+    /// Decimals exceed what an i128 share can represent
+    InvalidDecimals = 13,
+    /// Participant index is out of range
+    InvalidIndex = 14,
+    /// Sink is missing on a PercentageWithSink template, or set on another type
+    InvalidSink = 15,
+    /// Contract admin has already been set
+    AlreadyInitialized = 16,
+    /// Contract admin has not been set
+    NotInitialized = 17,
+    /// Recurring interval must be positive
+    InvalidInterval = 18,
+    /// No recurring schedule exists under the given ID
+    ScheduleNotFound = 19,
+    /// The recurring interval hasn't elapsed since the last run
+    ScheduleNotDue = 20,
+    /// The linked escrow contract has no split under the given ID
+    SplitNotFound = 21,
+    /// Template is archived and can't be scheduled or run
+    TemplateArchived = 22,
+    /// No escrow contract is linked to create splits in
+    EscrowNotLinked = 23,
 }
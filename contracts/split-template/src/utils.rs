@@ -2,6 +2,9 @@
 
 use soroban_sdk::{Bytes, Env, String};
 
+/// Most decimal places `format_decimal` accepts (an i128 has at most 39 digits).
+pub const MAX_DECIMALS: u32 = 38;
+
 /// Convert a slice of bytes to a hex string (uppercase).
 ///
 /// No_std compatible implementation without external dependencies.
@@ -40,3 +43,83 @@ pub fn hash_to_hex_upper(env: &Env, hash: &[u8; 32]) -> String {
     let hex_str = core::str::from_utf8(&hex_bytes[..idx]).unwrap_or("0");
     String::from_str(env, hex_str)
 }
+
+/// Render a fixed-point integer with the decimal point inserted.
+///
+/// `format_decimal(env, 100_0000000, 7)` yields `"100.0000000"`. Leading
+/// zeros are padded so `5` with 2 decimals becomes `"0.05"`. `decimals`
+/// must not exceed `MAX_DECIMALS`.
+pub fn format_decimal(env: &Env, value: i128, decimals: u32) -> String {
+    let decimals = decimals.min(MAX_DECIMALS) as usize;
+
+    // Collect digits least-significant first
+    let mut digits = [0u8; 48];
+    let mut len = 0;
+    let mut rest = value.unsigned_abs();
+    while rest > 0 || len <= decimals {
+        digits[len] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        len += 1;
+    }
+
+    let mut out = [0u8; 48];
+    let mut idx = 0;
+    if value < 0 {
+        out[idx] = b'-';
+        idx += 1;
+    }
+    for i in (0..len).rev() {
+        out[idx] = digits[i];
+        idx += 1;
+        if i == decimals && decimals > 0 {
+            out[idx] = b'.';
+            idx += 1;
+        }
+    }
+
+    // Safe because we only wrote ASCII digits, '-' and '.'
+    let formatted = core::str::from_utf8(&out[..idx]).unwrap_or("0");
+    String::from_str(env, formatted)
+}
+
+/// Describe one run of a recurring schedule as `"<name> #<schedule_id>.<run>"`.
+///
+/// The escrow contract hashes the description into the escrow ID, so giving
+/// each run its own keeps runs created in the same ledger from colliding.
+/// `name` must fit the template name limit.
+pub fn recurring_run_description(env: &Env, name: &String, schedule_id: u32, run: u32) -> String {
+    // 280-byte name plus " #", two u32s of up to 10 digits, and "."
+    let mut out = [0u8; 304];
+    let name_len = (name.len() as usize).min(280);
+    name.copy_into_slice(&mut out[..name_len]);
+
+    let mut idx = name_len;
+    out[idx] = b' ';
+    out[idx + 1] = b'#';
+    idx += 2;
+    idx = write_u32(&mut out, idx, schedule_id);
+    out[idx] = b'.';
+    idx = write_u32(&mut out, idx + 1, run);
+
+    String::from_bytes(env, &out[..idx])
+}
+
+/// Write `value` in decimal at `out[idx..]`, returning the index past it.
+fn write_u32(out: &mut [u8], idx: usize, value: u32) -> usize {
+    let mut digits = [0u8; 10];
+    let mut len = 0;
+    let mut rest = value;
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        len += 1;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    for i in 0..len {
+        out[idx + i] = digits[len - 1 - i];
+    }
+    idx + len
+}